@@ -0,0 +1,31 @@
+// Compares day 18's search through the key graph with the simpler search tile by tile.
+use advent_of_code_2019::error::Error;
+use advent_of_code_2019::solution::{Answer, Solution};
+use advent_of_code_2019::year2019::day18::Day18;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+
+fn compare(
+    c: &mut Criterion,
+    vault: &Day18,
+    part: &str,
+    solve: fn(&Day18) -> Result<Answer, Error>,
+) {
+    let robots = part == "part two";
+    let mut group = c.benchmark_group(format!("2019-day18 {}", part));
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_millis(500));
+    group.bench_function("key graph", |b| b.iter(|| solve(vault)));
+    group.bench_function("tiles", |b| b.iter(|| vault.steps_without_graph(robots)));
+    group.finish();
+}
+
+fn search(c: &mut Criterion) {
+    let input = std::fs::read_to_string("data/2019/day18.txt").expect("Failed to open input");
+    let vault = Day18::parse(&input).expect("Invalid input");
+    compare(c, &vault, "part one", Day18::part_one);
+    compare(c, &vault, "part two", Day18::part_two);
+}
+
+criterion_group!(benches, search);
+criterion_main!(benches);
@@ -0,0 +1,62 @@
+// Micro-benchmarks of the Intcode interpreter, on programs from the puzzles.
+use advent_of_code_2019::intcode::IntCode;
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+
+fn load(day: u8) -> IntCode {
+    let path = format!("data/2019/day{:02}.txt", day);
+    let program = std::fs::read_to_string(&path).expect("Failed to open input");
+    program.parse().expect("Could not parse program")
+}
+
+// The BOOST program in sensor boost mode: a long-running computation.
+fn boost(c: &mut Criterion) {
+    let intcode = load(9);
+    c.bench_function("intcode boost", |b| {
+        b.iter_batched(
+            || intcode.clone(),
+            |mut intcode| {
+                intcode.add_input(2);
+                intcode.execute().expect("Program failed");
+                intcode.get_output()
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+// A single probe of the tractor beam: a short program, run from scratch every time.
+fn probe(c: &mut Criterion) {
+    let intcode = load(19);
+    c.bench_function("intcode probe", |b| {
+        b.iter(|| {
+            let mut drone = intcode.clone();
+            drone.add_input(black_box(30));
+            drone.add_input(black_box(40));
+            drone.execute().expect("Program failed");
+            drone.get_output()
+        })
+    });
+}
+
+// Booting the network: fifty computers, each run until it needs input.
+fn network(c: &mut Criterion) {
+    let intcode = load(23);
+    c.bench_function("intcode network", |b| {
+        b.iter(|| {
+            let mut outputs = 0;
+            for address in 0..50 {
+                let mut computer = intcode.clone();
+                computer.add_input(address);
+                computer.add_input(-1);
+                computer.execute().expect("Program failed");
+                while computer.get_output().is_some() {
+                    outputs += 1;
+                }
+            }
+            outputs
+        })
+    });
+}
+
+criterion_group!(benches, boost, probe, network);
+criterion_main!(benches);
@@ -0,0 +1,30 @@
+// Times each day's parse and solve phases, on the inputs in `data/YYYY/`.
+//
+// Some days take seconds, so run a subset with a filter: `cargo bench --bench solutions -- 2019-day18`.
+use advent_of_code_2019::solution::YEARS;
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::time::Duration;
+
+fn solutions(c: &mut Criterion) {
+    let entries = YEARS
+        .iter()
+        .flat_map(|year| year.days.iter().map(move |entry| (year.year, entry)));
+    for (year, entry) in entries {
+        let path = format!("data/{}/day{:02}.txt", year, entry.day);
+        let input = match std::fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(_) => continue,
+        };
+        let mut group = c.benchmark_group(format!("{}-day{:02}", year, entry.day));
+        group.sample_size(10);
+        group.warm_up_time(Duration::from_millis(500));
+        group.bench_function("parse", |b| b.iter(|| (entry.parse)(&input)));
+        let solution = (entry.parse)(&input).expect("Invalid input");
+        group.bench_function("part one", |b| b.iter(|| solution.part_one()));
+        group.bench_function("part two", |b| b.iter(|| solution.part_two()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, solutions);
+criterion_main!(benches);
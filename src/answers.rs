@@ -0,0 +1,182 @@
+//! Expected answers, against which we can check our solutions.
+//!
+//! Each answer is a line giving the day, the part and the expected answer, separated by
+//! whitespace.  Answers that are pictures have nothing after the part: the picture follows on
+//! lines starting with '|'.  Blank lines and lines starting with '#' are ignored.
+use crate::runner::{PartReport, Report};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A set of expected answers.
+pub struct Answers {
+    expected: HashMap<(u8, usize), Vec<String>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned when a file of answers can't be parsed.
+pub struct ParseAnswersError {
+    line: usize,
+}
+
+impl fmt::Display for ParseAnswersError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "invalid answer at line {}", self.line)
+    }
+}
+
+fn split_word(s: &str) -> (&str, &str) {
+    let s = s.trim_start();
+    s.split_at(s.find(char::is_whitespace).unwrap_or(s.len()))
+}
+
+impl FromStr for Answers {
+    type Err = ParseAnswersError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut expected = HashMap::new();
+        let mut picture = None;
+        for (index, line) in s.lines().enumerate() {
+            let error = ParseAnswersError { line: index + 1 };
+            if let Some(row) = line.strip_prefix('|') {
+                let key = picture.ok_or(error)?;
+                let rows: &mut Vec<String> = expected.get_mut(&key).unwrap();
+                rows.push(row.to_owned());
+                continue;
+            }
+            picture = None;
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (day, rest) = split_word(line);
+            let (part, rest) = split_word(rest);
+            let day = day.parse().map_err(|_| error)?;
+            let part = part.parse().ok();
+            let part = part.filter(|&part| part == 1 || part == 2).ok_or(error)?;
+            if expected.contains_key(&(day, part)) {
+                return Err(error);
+            }
+            let answer = rest.trim();
+            if answer.is_empty() {
+                picture = Some((day, part));
+                expected.insert((day, part), vec![]);
+            } else {
+                expected.insert((day, part), vec![answer.to_owned()]);
+            }
+        }
+        Ok(Self { expected })
+    }
+}
+
+/// An answer that isn't what we expected, or that we have no expectation for.
+pub struct Mismatch {
+    day: u8,
+    part: usize,
+    expected: Option<Vec<String>>,
+    actual: String,
+}
+
+// Pictures may have trailing spaces, which we don't insist on.
+fn same(expected: &[String], actual: &str) -> bool {
+    let expected = expected.iter().map(|line| line.trim_end());
+    expected.eq(actual.lines().map(str::trim_end))
+}
+
+impl Answers {
+    /// Compares reported answers with those we expect.
+    pub fn check(&self, reports: &[Report]) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+        for report in reports {
+            for &PartReport {
+                part, ref answer, ..
+            } in &report.parts
+            {
+                let actual = answer.to_string();
+                let expected = self.expected.get(&(report.day, part));
+                if expected.is_none_or(|expected| !same(expected, &actual)) {
+                    mismatches.push(Mismatch {
+                        day: report.day,
+                        part,
+                        expected: expected.cloned(),
+                        actual,
+                    });
+                }
+            }
+        }
+        mismatches
+    }
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(fmt, "Day {} part {}:", self.day, self.part)?;
+        match &self.expected {
+            Some(expected) => {
+                for line in expected {
+                    writeln!(fmt, "- {}", line)?;
+                }
+            }
+            None => writeln!(fmt, "  (no expected answer)")?,
+        }
+        for line in self.actual.lines() {
+            writeln!(fmt, "+ {}", line)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+    use std::time::Duration;
+
+    fn report(day: u8, answers: Vec<Answer>) -> Report {
+        let parts = answers
+            .into_iter()
+            .enumerate()
+            .map(|(index, answer)| PartReport {
+                part: index + 1,
+                answer,
+                time: Duration::default(),
+            })
+            .collect();
+        Report {
+            year: 2019,
+            day,
+            parse: Duration::default(),
+            parts,
+        }
+    }
+
+    #[test]
+    fn check() {
+        let answers: Answers = "# comment\n1 1 12\n1 2 some text\n\n2 1\n|#.\n|.#  \n"
+            .parse()
+            .unwrap();
+        let picture = Answer::Image(vec!["#.".to_owned(), ".#".to_owned()]);
+        let reports = [
+            report(
+                1,
+                vec![Answer::Number(12), Answer::Text("some text".to_owned())],
+            ),
+            report(2, vec![picture]),
+        ];
+        assert!(answers.check(&reports).is_empty());
+
+        let reports = [report(1, vec![Answer::Number(13)]), report(3, vec![])];
+        let mismatches = answers.check(&reports);
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].to_string(), "Day 1 part 1:\n- 12\n+ 13\n");
+    }
+
+    #[test]
+    fn errors() {
+        let line = |s: &str| s.parse::<Answers>().err().map(|err| err.line);
+        assert_eq!(line("1 3 12"), Some(1));
+        assert_eq!(line("1 1 12\n1 1 13"), Some(2));
+        assert_eq!(line("|#."), Some(1));
+        assert_eq!(line("x 1 12"), Some(1));
+    }
+}
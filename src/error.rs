@@ -0,0 +1,111 @@
+//! The error that solutions report when their input isn't valid.
+use crate::intcode::{ExecuteError, ParseIntCodeError};
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a puzzle couldn't be solved: the reason, the line of the input if the problem is on one
+/// line, and the puzzle once the runner knows it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    puzzle: Option<(u16, u8)>,
+    line: Option<usize>,
+    reason: String,
+}
+
+impl Error {
+    /// A problem with the input as a whole.
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            puzzle: None,
+            line: None,
+            reason: reason.into(),
+        }
+    }
+
+    /// A problem with one line of the input, counting from 1.
+    pub fn at_line(line: usize, reason: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            ..Self::new(reason)
+        }
+    }
+
+    /// Records which puzzle the error comes from.
+    pub fn on_day(self, year: u16, day: u8) -> Self {
+        Self {
+            puzzle: Some((year, day)),
+            ..self
+        }
+    }
+
+    /// The day of the puzzle, once it is known.
+    pub fn day(&self) -> Option<u8> {
+        self.puzzle.map(|(_, day)| day)
+    }
+
+    /// The line of the input, counting from 1, if the problem is on one line.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// What went wrong.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((year, day)) = self.puzzle {
+            write!(fmt, "day {} of {}: ", day, year)?;
+        }
+        if let Some(line) = self.line {
+            write!(fmt, "line {}: ", line)?;
+        }
+        write!(fmt, "{}", self.reason)
+    }
+}
+
+impl std::error::Error for Error {}
+
+// Intcode programs are a single line, so the error says where in the line the problem is.
+impl From<ParseIntCodeError> for Error {
+    fn from(err: ParseIntCodeError) -> Self {
+        Self::new(format!("invalid Intcode program: {}", err))
+    }
+}
+
+impl From<ExecuteError> for Error {
+    fn from(err: ExecuteError) -> Self {
+        Self::new(format!("Intcode program failed: {}", err))
+    }
+}
+
+/// Parses each line of the input, reporting the first line that isn't valid.  `what` describes
+/// what a line should be, for the error message.
+pub fn parse_lines<T: FromStr>(input: &str, what: &str) -> Result<Vec<T>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            line.parse()
+                .map_err(|_| Error::at_line(index + 1, format!("invalid {}: {:?}", what, line)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines() {
+        assert_eq!(parse_lines::<u8>("1\n2\n", "number"), Ok(vec![1, 2]));
+        let err = parse_lines::<u8>("1\nx\n3", "number").unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(
+            err.on_day(2019, 1).to_string(),
+            "day 1 of 2019: line 2: invalid number: \"x\""
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! Points and directions on a square grid, and dense grids read from text.
+//!
+//! Rows are numbered down the page, as they are when a grid is read from text: so `Up` is
+//! towards smaller `y`.  Puzzles that describe the y-axis pointing upwards (such as the wires of
+//! 2019's day 3 and the hull of day 11) just see a mirror image of their world, which changes no
+//! distances.
+use crate::error::Error;
+use std::convert::TryFrom;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+/// A point on the grid.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    /// The column, increasing to the right.
+    pub x: i64,
+    /// The row, increasing downwards.
+    pub y: i64,
+}
+
+impl Point {
+    /// The point from which many puzzles start.
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    /// The point at a column and row.
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// The adjacent point in a direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The four points that share an edge with this one.
+    pub fn neighbours(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .iter()
+            .map(move |&direction| self.step(direction))
+    }
+
+    /// The eight points that share an edge or a corner with this one.
+    pub fn all_neighbours(self) -> impl Iterator<Item = Self> {
+        iproduct!(-1..=1, -1..=1)
+            .filter(|&offset| offset != (0, 0))
+            .map(move |(dx, dy)| self + Self::new(dx, dy))
+    }
+
+    /// The number of steps between two points, moving along rows and columns.
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+/// A direction along the rows or columns of the grid.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// Towards the previous row.
+    Up,
+    /// Towards the next column.
+    Right,
+    /// Towards the next row.
+    Down,
+    /// Towards the previous column.
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// The step that moves one place in this direction.
+    pub fn offset(self) -> Point {
+        match self {
+            Self::Up => Point::new(0, -1),
+            Self::Right => Point::new(1, 0),
+            Self::Down => Point::new(0, 1),
+            Self::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// The direction after turning a quarter anticlockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Right => Self::Up,
+            Self::Down => Self::Right,
+            Self::Left => Self::Down,
+        }
+    }
+
+    /// The direction after turning a quarter clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    /// The direction after turning around.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+            Self::Left => Self::Right,
+        }
+    }
+}
+
+/// A rectangular grid with a value at every point, whose top left corner is the origin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid with the same value everywhere.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Reads a grid from text, converting each character into a value.  Every line must be the
+    /// same length, though blank lines at the end are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Fails, giving the line, if a line is the wrong length or has a character that can't be
+    /// converted.
+    pub fn parse<F, E>(text: &str, convert: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Self::read(text, None, convert)
+    }
+
+    /// Reads a grid from text as `parse` does, but lines may be shorter than the longest, as when
+    /// an editor has trimmed trailing spaces.  Short lines are filled out with `padding` before
+    /// converting.
+    ///
+    /// # Errors
+    ///
+    /// Fails, giving the line, if a line has a character that can't be converted.
+    pub fn parse_padded<F, E>(text: &str, padding: char, convert: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        Self::read(text, Some(padding), convert)
+    }
+
+    fn read<F, E>(text: &str, padding: Option<char>, mut convert: F) -> Result<Self, Error>
+    where
+        F: FnMut(char) -> Result<T, E>,
+    {
+        let mut lines = text.lines().collect::<Vec<_>>();
+        while lines.last().is_some_and(|line| line.is_empty()) {
+            lines.pop();
+        }
+        // Without padding, the first line sets the width that the others must match.
+        let mut lengths = lines.iter().map(|line| line.chars().count());
+        let width = match padding {
+            Some(_) => lengths.max(),
+            None => lengths.next(),
+        };
+        let width = width.unwrap_or(0);
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (index, line) in lines.iter().enumerate() {
+            let length = line.chars().count();
+            if length != width && padding.is_none() {
+                let reason = format!("expected a row of {} tiles", width);
+                return Err(Error::at_line(index + 1, reason));
+            }
+            let fill = padding.into_iter().flat_map(std::iter::repeat);
+            for c in line.chars().chain(fill.take(width - length)) {
+                let value = convert(c)
+                    .map_err(|_| Error::at_line(index + 1, format!("unexpected tile: {:?}", c)))?;
+                cells.push(value);
+            }
+        }
+        Ok(Self {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    /// The number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|&x| x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|&y| y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    /// Whether a point is on the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        self.index(point).is_some()
+    }
+
+    /// The value at a point, or `None` if the point is off the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|index| &self.cells[index])
+    }
+
+    /// The value at a point, to change, or `None` if the point is off the grid.
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(move |index| &mut self.cells[index])
+    }
+
+    /// Every point on the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width as i64;
+        let height = self.height as i64;
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every point on the grid with its value, a row at a time.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The first point, a row at a time, whose value satisfies a predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(point, _)| point)
+    }
+
+    /// Draws each row of the grid as text, converting each value into a character.
+    pub fn render_rows(&self, mut draw: impl FnMut(&T) -> char) -> Vec<String> {
+        if self.width == 0 {
+            return vec![String::new(); self.height];
+        }
+        let rows = self.cells.chunks(self.width);
+        rows.map(|row| row.iter().map(&mut draw).collect())
+            .collect()
+    }
+
+    /// Draws the grid as text, a line for each row.
+    pub fn render(&self, draw: impl FnMut(&T) -> char) -> String {
+        self.render_rows(draw).join("\n")
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", point))
+    }
+}
+
+/// The smallest and largest coordinates of some points, as the top left and bottom right corners
+/// of the rectangle that holds them, or `None` if there are no points.
+pub fn bounds(points: impl IntoIterator<Item = Point>) -> Option<(Point, Point)> {
+    points.into_iter().fold(None, |bounds, point| {
+        let (low, high) = bounds.unwrap_or((point, point));
+        let low = Point::new(low.x.min(point.x), low.y.min(point.y));
+        let high = Point::new(high.x.max(point.x), high.y.max(point.y));
+        Some((low, high))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        for &direction in &Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            let there_and_back = Point::ORIGIN.step(direction).step(direction.opposite());
+            assert_eq!(there_and_back, Point::ORIGIN);
+        }
+        assert_eq!(Point::ORIGIN.step(Direction::Up), Point::new(0, -1));
+    }
+
+    #[test]
+    fn neighbours() {
+        let point = Point::new(2, 3);
+        assert_eq!(point.neighbours().count(), 4);
+        assert!(point.neighbours().all(|p| p.manhattan(point) == 1));
+        let all = point.all_neighbours().collect::<Vec<_>>();
+        assert_eq!(all.len(), 8);
+        assert!(all.contains(&Point::new(1, 2)));
+        assert!(!all.contains(&point));
+    }
+
+    #[test]
+    fn parsing() {
+        let grid = Grid::parse("#.#\n..#\n\n", |c| Ok::<_, ()>(c == '#')).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&true));
+        assert_eq!(grid.get(Point::new(3, 1)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.position(|&wall| !wall), Some(Point::new(1, 0)));
+        assert_eq!(
+            grid.render(|&wall| if wall { '#' } else { '.' }),
+            "#.#\n..#"
+        );
+
+        let err = Grid::parse("##\n#", Ok::<_, ()>).unwrap_err();
+        assert_eq!(err.line(), Some(2));
+        let err = Grid::parse("##\n#x", |c| if c == '#' { Ok(c) } else { Err(()) });
+        assert_eq!(err.unwrap_err().reason(), "unexpected tile: 'x'");
+
+        let grid = Grid::parse_padded(" #\n\n###\n", ' ', Ok::<_, ()>).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid.render(|&c| c), " # \n   \n###");
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = vec![Point::new(1, -2), Point::new(-3, 4), Point::new(0, 0)];
+        let expected = (Point::new(-3, -2), Point::new(1, 4));
+        assert_eq!(bounds(points), Some(expected));
+        assert_eq!(bounds(vec![]), None);
+    }
+}
@@ -1,14 +1,27 @@
+//! A virtual machine for running Intcode programs.
+pub use self::diff::Diff;
+pub use self::format::ParseIntCodeError;
 use std::collections::VecDeque;
 use std::convert::TryFrom;
+use std::fmt;
 use std::str::FromStr;
+use std::sync::Arc;
 
+mod diff;
+mod format;
+
+/// An Intcode computer, loaded with a program.
+///
+/// The computer reads values from an input queue and writes values to an output queue.
 #[derive(Clone, Debug, Default)]
 pub struct IntCode {
+    original: Arc<Vec<i64>>,
     memory: Vec<i64>,
     instruction_pointer: usize,
     input: VecDeque<i64>,
     output: VecDeque<i64>,
     relative_base: i64,
+    history: Option<Vec<Undo>>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -32,27 +45,164 @@ impl TryFrom<i64> for Mode {
     }
 }
 
-#[derive(PartialEq, Eq)]
+/// Why a program stopped running.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ExecuteResult {
+    /// The program halted.
     Done,
+    /// The program is waiting for input: add some and execute again.
     InputNeeded,
 }
 
+/// Why a program couldn't go on running.  The computer is left as it was before the instruction
+/// at fault.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExecuteError {
+    /// The instruction has an opcode that we don't know.
+    InvalidOpcode {
+        /// Where the instruction is.
+        instruction_pointer: usize,
+        /// The opcode, including the parameter modes.
+        opcode: i64,
+    },
+
+    /// A parameter has a mode that we don't know, or is a destination in immediate mode.
+    InvalidMode {
+        /// Where the instruction is.
+        instruction_pointer: usize,
+        /// The opcode, including the parameter modes.
+        opcode: i64,
+        /// Which parameter, counting from 1.
+        parameter: usize,
+    },
+
+    /// The instruction uses an address below zero.
+    NegativeAddress {
+        /// Where the instruction is.
+        instruction_pointer: usize,
+        /// The address.
+        address: i64,
+    },
+
+    /// The instruction computes a value too large for an `i64`.
+    Overflow {
+        /// Where the instruction is.
+        instruction_pointer: usize,
+    },
+}
+
+impl fmt::Display for ExecuteError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidOpcode {
+                instruction_pointer,
+                opcode,
+            } => write!(
+                fmt,
+                "invalid opcode {} at address {}",
+                opcode, instruction_pointer
+            ),
+            Self::InvalidMode {
+                instruction_pointer,
+                opcode,
+                parameter,
+            } => write!(
+                fmt,
+                "invalid mode for parameter {} of opcode {} at address {}",
+                parameter, opcode, instruction_pointer
+            ),
+            Self::NegativeAddress {
+                instruction_pointer,
+                address,
+            } => write!(
+                fmt,
+                "instruction at address {} uses negative address {}",
+                instruction_pointer, address
+            ),
+            Self::Overflow {
+                instruction_pointer,
+            } => write!(
+                fmt,
+                "instruction at address {} overflows",
+                instruction_pointer
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ExecuteError {}
+
+// Everything needed to reverse the effect of a single instruction.
+#[derive(Copy, Clone, Debug)]
+struct Undo {
+    instruction_pointer: usize,
+    relative_base: i64,
+    write: Option<(usize, i64)>,
+    input: Option<i64>,
+    output: Option<i64>,
+}
+
+impl Undo {
+    fn new(instruction_pointer: usize, relative_base: i64) -> Self {
+        Self {
+            instruction_pointer,
+            relative_base,
+            write: None,
+            input: None,
+            output: None,
+        }
+    }
+}
+
+/// Describes the instruction that most recently wrote to some address.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Write {
+    /// The position of the instruction in the recorded history.
+    pub step: usize,
+    /// Where the instruction was.
+    pub instruction_pointer: usize,
+    /// The value that the instruction overwrote.
+    pub previous: i64,
+}
+
+/// A saved copy of an `IntCode`'s state, which it can later be restored to.
+#[derive(Clone, Debug)]
+pub struct Checkpoint {
+    state: IntCode,
+}
+
 impl IntCode {
+    /// Creates a computer running the given program.
     pub fn new(program: Vec<i64>) -> Self {
         Self {
+            original: Arc::new(program.clone()),
             memory: program,
             instruction_pointer: 0,
             input: VecDeque::new(),
             output: VecDeque::new(),
             relative_base: 0,
+            history: None,
         }
     }
 
+    /// Restores the program that we were created with, discarding any pending input and output.
+    pub fn reset(&mut self) {
+        self.memory = self.original.to_vec();
+        self.instruction_pointer = 0;
+        self.input.clear();
+        self.output.clear();
+        self.relative_base = 0;
+        if let Some(history) = &mut self.history {
+            history.clear();
+        }
+    }
+
+    /// Reads from memory.  Memory beyond the end of the program is zero.
     pub fn read(&self, address: usize) -> i64 {
         self.memory.get(address).cloned().unwrap_or(0)
     }
 
+    /// Writes to memory, extending it if necessary.
     pub fn write(&mut self, address: usize, value: i64) {
         if address >= self.memory.len() {
             self.memory.resize(address + 1, 0);
@@ -60,10 +210,12 @@ impl IntCode {
         self.memory[address] = value
     }
 
+    /// Adds a value to the end of the input queue.
     pub fn add_input(&mut self, value: i64) {
         self.input.push_back(value)
     }
 
+    /// Takes a value from the front of the output queue.
     pub fn get_output(&mut self) -> Option<i64> {
         self.output.pop_front()
     }
@@ -72,123 +224,510 @@ impl IntCode {
         self.read(self.instruction_pointer)
     }
 
-    fn get_parameter(&self, opcode: i64, parameter: usize) -> i64 {
+    fn get_mode(&self, opcode: i64, parameter: usize) -> Result<Mode, ExecuteError> {
         let mode = (opcode / 10i64.pow(parameter as u32 + 1)) % 10;
-        let mode = Mode::try_from(mode).expect("Invalid mode");
+        Mode::try_from(mode).map_err(|()| ExecuteError::InvalidMode {
+            instruction_pointer: self.instruction_pointer,
+            opcode,
+            parameter,
+        })
+    }
+
+    fn get_parameter(&self, opcode: i64, parameter: usize) -> Result<i64, ExecuteError> {
         let value = self.read(self.instruction_pointer + parameter);
-        match mode {
-            Mode::Position => self.read(value as usize),
+        let value = match self.get_mode(opcode, parameter)? {
+            Mode::Position => self.read(self.address(value)?),
             Mode::Immediate => value,
-            Mode::Relative => self.read((self.relative_base + value) as usize),
-        }
+            Mode::Relative => self.read(self.address(self.relative(value)?)?),
+        };
+        Ok(value)
     }
 
-    fn get_dest(&self, opcode: i64, parameter: usize) -> i64 {
-        let mode = (opcode / 10i64.pow(parameter as u32 + 1)) % 10;
-        let mode = Mode::try_from(mode).expect("Invalid mode");
+    fn get_dest(&self, opcode: i64, parameter: usize) -> Result<usize, ExecuteError> {
         let value = self.read(self.instruction_pointer + parameter);
-        match mode {
-            Mode::Position => value,
-            Mode::Immediate => panic!("Destination parameter in immediate mode"),
-            Mode::Relative => value + self.relative_base,
+        match self.get_mode(opcode, parameter)? {
+            Mode::Position => self.address(value),
+            Mode::Immediate => Err(ExecuteError::InvalidMode {
+                instruction_pointer: self.instruction_pointer,
+                opcode,
+                parameter,
+            }),
+            Mode::Relative => self.address(self.relative(value)?),
         }
     }
 
-    pub fn execute(&mut self) -> ExecuteResult {
-        let mut result = ExecuteResult::Done;
+    fn relative(&self, value: i64) -> Result<i64, ExecuteError> {
+        self.check(self.relative_base.checked_add(value))
+    }
+
+    fn address(&self, value: i64) -> Result<usize, ExecuteError> {
+        if value < 0 {
+            return Err(ExecuteError::NegativeAddress {
+                instruction_pointer: self.instruction_pointer,
+                address: value,
+            });
+        }
+        Ok(value as usize)
+    }
+
+    // Reports arithmetic that overflowed.
+    fn check(&self, value: Option<i64>) -> Result<i64, ExecuteError> {
+        value.ok_or(ExecuteError::Overflow {
+            instruction_pointer: self.instruction_pointer,
+        })
+    }
+
+    /// Executes until the program halts or needs input.
+    pub fn execute(&mut self) -> Result<ExecuteResult, ExecuteError> {
         loop {
-            let opcode = self.get_opcode();
-            match opcode % 100 {
-                1 => {
-                    // Addition.
-                    let x = self.get_parameter(opcode, 1);
-                    let y = self.get_parameter(opcode, 2);
-                    let dest = self.get_dest(opcode, 3) as usize;
-                    self.write(dest, x + y);
-                    self.instruction_pointer += 4;
-                }
-                2 => {
-                    // Multiplication.
-                    let x = self.get_parameter(opcode, 1);
-                    let y = self.get_parameter(opcode, 2);
-                    let dest = self.get_dest(opcode, 3) as usize;
-                    self.write(dest, x * y);
-                    self.instruction_pointer += 4;
-                }
-                3 => {
-                    // Read input and save it to address.
-                    if let Some(value) = self.input.pop_front() {
-                        let dest = self.get_dest(opcode, 1) as usize;
-                        self.write(dest, value);
-                        self.instruction_pointer += 2;
-                    } else {
-                        result = ExecuteResult::InputNeeded;
-                        break;
-                    }
-                }
-                4 => {
-                    // Output value.
-                    let value = self.get_parameter(opcode, 1);
-                    self.output.push_back(value);
-                    self.instruction_pointer += 2;
-                }
-                5 => {
-                    // Jump if true.
-                    let value = self.get_parameter(opcode, 1);
-                    if value != 0 {
-                        self.instruction_pointer = self.get_parameter(opcode, 2) as usize;
-                    } else {
-                        self.instruction_pointer += 3;
-                    }
-                }
-                6 => {
-                    // Jump if false.
-                    let value = self.get_parameter(opcode, 1);
-                    if value == 0 {
-                        self.instruction_pointer = self.get_parameter(opcode, 2) as usize;
-                    } else {
-                        self.instruction_pointer += 3;
-                    }
-                }
-                7 => {
-                    // Less than.
-                    let x = self.get_parameter(opcode, 1);
-                    let y = self.get_parameter(opcode, 2);
-                    let value = if x < y { 1 } else { 0 };
-                    let dest = self.get_dest(opcode, 3) as usize;
-                    self.write(dest, value);
-                    self.instruction_pointer += 4;
-                }
-                8 => {
-                    // Equals.
-                    let x = self.get_parameter(opcode, 1);
-                    let y = self.get_parameter(opcode, 2);
-                    let value = if x == y { 1 } else { 0 };
-                    let dest = self.get_dest(opcode, 3) as usize;
-                    self.write(dest, value);
-                    self.instruction_pointer += 4;
+            if let Some(result) = self.step()? {
+                return Ok(result);
+            }
+        }
+    }
+
+    /// Executes a single instruction, returning a result only if the program can make no further
+    /// progress.
+    pub fn step(&mut self) -> Result<Option<ExecuteResult>, ExecuteError> {
+        let mut undo = Undo::new(self.instruction_pointer, self.relative_base);
+        let opcode = self.get_opcode();
+        match opcode % 100 {
+            1 => {
+                // Addition.
+                let x = self.get_parameter(opcode, 1)?;
+                let y = self.get_parameter(opcode, 2)?;
+                let dest = self.get_dest(opcode, 3)?;
+                let value = self.check(x.checked_add(y))?;
+                self.store(&mut undo, dest, value);
+                self.instruction_pointer += 4;
+            }
+            2 => {
+                // Multiplication.
+                let x = self.get_parameter(opcode, 1)?;
+                let y = self.get_parameter(opcode, 2)?;
+                let dest = self.get_dest(opcode, 3)?;
+                let value = self.check(x.checked_mul(y))?;
+                self.store(&mut undo, dest, value);
+                self.instruction_pointer += 4;
+            }
+            3 => {
+                // Read input and save it to address.
+                let dest = self.get_dest(opcode, 1)?;
+                let value = match self.input.pop_front() {
+                    Some(value) => value,
+                    None => return Ok(Some(ExecuteResult::InputNeeded)),
+                };
+                undo.input = Some(value);
+                self.store(&mut undo, dest, value);
+                self.instruction_pointer += 2;
+            }
+            4 => {
+                // Output value.
+                let value = self.get_parameter(opcode, 1)?;
+                undo.output = Some(value);
+                self.output.push_back(value);
+                self.instruction_pointer += 2;
+            }
+            5 => {
+                // Jump if true.
+                let value = self.get_parameter(opcode, 1)?;
+                if value != 0 {
+                    let target = self.get_parameter(opcode, 2)?;
+                    self.instruction_pointer = self.address(target)?;
+                } else {
+                    self.instruction_pointer += 3;
                 }
-                9 => {
-                    // Relative base offset.
-                    let delta = self.get_parameter(opcode, 1);
-                    self.relative_base += delta;
-                    self.instruction_pointer += 2;
+            }
+            6 => {
+                // Jump if false.
+                let value = self.get_parameter(opcode, 1)?;
+                if value == 0 {
+                    let target = self.get_parameter(opcode, 2)?;
+                    self.instruction_pointer = self.address(target)?;
+                } else {
+                    self.instruction_pointer += 3;
                 }
-                99 => break,
-                _ => panic!("Unexpected opcode: {}!", opcode),
+            }
+            7 => {
+                // Less than.
+                let x = self.get_parameter(opcode, 1)?;
+                let y = self.get_parameter(opcode, 2)?;
+                let value = if x < y { 1 } else { 0 };
+                let dest = self.get_dest(opcode, 3)?;
+                self.store(&mut undo, dest, value);
+                self.instruction_pointer += 4;
+            }
+            8 => {
+                // Equals.
+                let x = self.get_parameter(opcode, 1)?;
+                let y = self.get_parameter(opcode, 2)?;
+                let value = if x == y { 1 } else { 0 };
+                let dest = self.get_dest(opcode, 3)?;
+                self.store(&mut undo, dest, value);
+                self.instruction_pointer += 4;
+            }
+            9 => {
+                // Relative base offset.
+                let delta = self.get_parameter(opcode, 1)?;
+                self.relative_base = self.relative(delta)?;
+                self.instruction_pointer += 2;
+            }
+            99 => return Ok(Some(ExecuteResult::Done)),
+            _ => {
+                return Err(ExecuteError::InvalidOpcode {
+                    instruction_pointer: self.instruction_pointer,
+                    opcode,
+                })
             }
         }
-        result
+        if let Some(history) = &mut self.history {
+            history.push(undo);
+        }
+        Ok(None)
+    }
+
+    // Writes on behalf of an instruction, remembering what was overwritten.
+    fn store(&mut self, undo: &mut Undo, address: usize, value: i64) {
+        undo.write = Some((address, self.read(address)));
+        self.write(address, value);
+    }
+}
+
+// Support for debugging programs.
+impl IntCode {
+    /// Executes until the program halts or needs input, or until it arrives at one of the
+    /// breakpoints: in which case we return `None`.
+    pub fn execute_until(
+        &mut self,
+        breakpoints: &[usize],
+    ) -> Result<Option<ExecuteResult>, ExecuteError> {
+        loop {
+            if let Some(result) = self.step()? {
+                return Ok(Some(result));
+            }
+            if breakpoints.contains(&self.instruction_pointer) {
+                return Ok(None);
+            }
+        }
+    }
+
+    /// Start recording enough information about each instruction to allow it to be undone.
+    pub fn record_history(&mut self) {
+        self.history.get_or_insert_with(Vec::new);
+    }
+
+    /// The number of instructions that we are able to step back through.
+    pub fn steps_recorded(&self) -> usize {
+        self.history.as_ref().map_or(0, |history| history.len())
+    }
+
+    /// Reverses the most recently executed instruction, returning false if there is no record of
+    /// it.
+    ///
+    /// An input that the instruction consumed is returned to the front of the input queue, and an
+    /// output that it produced is withdrawn from the output queue if it has not yet been taken.
+    pub fn step_back(&mut self) -> bool {
+        let undo = match self.history.as_mut().and_then(|history| history.pop()) {
+            Some(undo) => undo,
+            None => return false,
+        };
+        self.instruction_pointer = undo.instruction_pointer;
+        self.relative_base = undo.relative_base;
+        if let Some((address, value)) = undo.write {
+            self.write(address, value);
+        }
+        if let Some(value) = undo.input {
+            self.input.push_front(value);
+        }
+        if undo.output.is_some() {
+            self.output.pop_back();
+        }
+        true
+    }
+
+    /// Searches the recorded history for the last instruction to write to an address.
+    pub fn last_write(&self, address: usize) -> Option<Write> {
+        let history = self.history.as_ref()?;
+        history
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(step, undo)| match undo.write {
+                Some((dest, previous)) if dest == address => Some(Write {
+                    step,
+                    instruction_pointer: undo.instruction_pointer,
+                    previous,
+                }),
+                _ => None,
+            })
+    }
+
+    /// Saves our state, so that we can later return to it.
+    pub fn mark(&self) -> Checkpoint {
+        Checkpoint {
+            state: self.clone(),
+        }
+    }
+
+    /// Returns to a saved state.
+    pub fn restore(&mut self, checkpoint: &Checkpoint) {
+        *self = checkpoint.state.clone();
+    }
+}
+
+// Loading and saving programs in other forms.
+impl IntCode {
+    /// Loads a program that may be text or binary, and may be gzipped.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseIntCodeError> {
+        format::decode(bytes).map(IntCode::new)
+    }
+
+    /// Our current memory, as comma-separated text.
+    pub fn to_text(&self) -> String {
+        format::encode_text(&self.memory)
+    }
+
+    /// Our current memory, in binary form, optionally gzipped.
+    pub fn to_binary(&self, compress: bool) -> Vec<u8> {
+        let bytes = format::encode_binary(&self.memory);
+        if compress {
+            format::compress(&bytes)
+        } else {
+            bytes
+        }
     }
 }
 
 impl FromStr for IntCode {
-    type Err = std::num::ParseIntError;
+    type Err = ParseIntCodeError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        s.trim()
+        format::decode_text(s).map(IntCode::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(program: &str, inputs: &[i64]) -> Vec<i64> {
+        let mut intcode: IntCode = program.parse().unwrap();
+        for &input in inputs {
+            intcode.add_input(input);
+        }
+        assert_eq!(intcode.execute(), Ok(ExecuteResult::Done));
+        std::iter::from_fn(|| intcode.get_output()).collect()
+    }
+
+    fn memory_after(program: &str) -> String {
+        let mut intcode: IntCode = program.parse().unwrap();
+        intcode.execute().unwrap();
+        intcode.to_text()
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(memory_after("1,0,0,0,99"), "2,0,0,0,99");
+        assert_eq!(memory_after("2,3,0,3,99"), "2,3,0,6,99");
+        assert_eq!(memory_after("2,4,4,5,99,0"), "2,4,4,5,99,9801");
+        assert_eq!(memory_after("1,1,1,4,99,5,6,0,99"), "30,1,1,4,2,5,6,0,99");
+        assert_eq!(
+            memory_after("1,9,10,3,2,3,11,0,99,30,40,50"),
+            "3500,9,10,70,2,3,11,0,99,30,40,50"
+        );
+    }
+
+    #[test]
+    fn modes() {
+        assert_eq!(memory_after("1002,4,3,4,33"), "1002,4,3,4,99");
+        assert_eq!(memory_after("1101,100,-1,4,0"), "1101,100,-1,4,99");
+    }
+
+    #[test]
+    fn comparisons() {
+        for &(program, input, expected) in &[
+            ("3,9,8,9,10,9,4,9,99,-1,8", 8, 1),
+            ("3,9,8,9,10,9,4,9,99,-1,8", 7, 0),
+            ("3,9,7,9,10,9,4,9,99,-1,8", 7, 1),
+            ("3,9,7,9,10,9,4,9,99,-1,8", 8, 0),
+            ("3,3,1108,-1,8,3,4,3,99", 8, 1),
+            ("3,3,1108,-1,8,3,4,3,99", 9, 0),
+            ("3,3,1107,-1,8,3,4,3,99", 7, 1),
+            ("3,3,1107,-1,8,3,4,3,99", 9, 0),
+        ] {
+            assert_eq!(run(program, &[input]), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn jumps() {
+        let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,\
+                       1002,21,125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,\
+                       1105,1,46,98,99";
+        assert_eq!(run(program, &[7]), vec![999]);
+        assert_eq!(run(program, &[8]), vec![1000]);
+        assert_eq!(run(program, &[9]), vec![1001]);
+        assert_eq!(
+            run("3,12,6,12,15,1,13,14,13,4,13,99,-1,0,1,9", &[0]),
+            vec![0]
+        );
+        assert_eq!(run("3,3,1105,-1,9,1101,0,0,12,4,12,99,1", &[5]), vec![1]);
+    }
+
+    #[test]
+    fn relative_mode_and_large_numbers() {
+        let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+        let expected = quine
             .split(',')
-            .map(|word| word.parse::<i64>())
-            .collect::<Result<Vec<_>, _>>()
-            .map(|p| IntCode::new(p.to_vec()))
+            .map(|n| n.parse().unwrap())
+            .collect::<Vec<i64>>();
+        assert_eq!(run(quine, &[]), expected);
+        assert_eq!(
+            run("1102,34915192,34915192,7,4,7,99,0", &[]),
+            vec![1_219_070_632_396_864]
+        );
+        assert_eq!(
+            run("104,1125899906842624,99", &[]),
+            vec![1_125_899_906_842_624]
+        );
+    }
+
+    #[test]
+    fn waits_for_input() {
+        let mut intcode: IntCode = "3,0,4,0,99".parse().unwrap();
+        assert_eq!(intcode.execute(), Ok(ExecuteResult::InputNeeded));
+        intcode.add_input(42);
+        assert_eq!(intcode.execute(), Ok(ExecuteResult::Done));
+        assert_eq!(intcode.get_output(), Some(42));
+    }
+
+    #[test]
+    fn execute_errors() {
+        for &(program, expected) in &[
+            (
+                "98",
+                ExecuteError::InvalidOpcode {
+                    instruction_pointer: 0,
+                    opcode: 98,
+                },
+            ),
+            (
+                "1101,0,0,5,301,0,0,0",
+                ExecuteError::InvalidMode {
+                    instruction_pointer: 4,
+                    opcode: 301,
+                    parameter: 1,
+                },
+            ),
+            (
+                "11101,1,1,0",
+                ExecuteError::InvalidMode {
+                    instruction_pointer: 0,
+                    opcode: 11101,
+                    parameter: 3,
+                },
+            ),
+            (
+                "1,-1,0,0",
+                ExecuteError::NegativeAddress {
+                    instruction_pointer: 0,
+                    address: -1,
+                },
+            ),
+            (
+                "1105,1,-4",
+                ExecuteError::NegativeAddress {
+                    instruction_pointer: 0,
+                    address: -4,
+                },
+            ),
+            (
+                "1102,9223372036854775807,2,0",
+                ExecuteError::Overflow {
+                    instruction_pointer: 0,
+                },
+            ),
+        ] {
+            let mut intcode: IntCode = program.parse().unwrap();
+            assert_eq!(intcode.execute(), Err(expected));
+            let stopped = intcode.clone();
+            assert_eq!(intcode.step(), Err(expected));
+            assert!(stopped.diff(&intcode).is_empty());
+        }
+    }
+
+    #[test]
+    fn step_back() {
+        let mut intcode: IntCode = "3,0,1,0,0,0,4,0,99".parse().unwrap();
+        intcode.record_history();
+        intcode.add_input(21);
+        intcode.execute().unwrap();
+        assert_eq!(intcode.steps_recorded(), 3);
+        assert_eq!(intcode.last_write(0).unwrap().previous, 21);
+
+        while intcode.step_back() {}
+        assert_eq!(intcode.to_text(), "3,0,1,0,0,0,4,0,99");
+        assert_eq!(intcode.get_output(), None);
+        intcode.execute().unwrap();
+        assert_eq!(intcode.get_output(), Some(42));
+    }
+
+    #[test]
+    fn checkpoints() {
+        let mut intcode: IntCode = "3,0,4,0,99".parse().unwrap();
+        let checkpoint = intcode.mark();
+        intcode.add_input(1);
+        intcode.execute().unwrap();
+        intcode.restore(&checkpoint);
+        intcode.add_input(2);
+        intcode.execute().unwrap();
+        assert_eq!(intcode.get_output(), Some(2));
+    }
+
+    #[test]
+    fn formats() {
+        let text = "1,-2, 3\n# comment\n  400000000000 99";
+        let intcode: IntCode = text.parse().unwrap();
+        assert_eq!(intcode.to_text(), "1,-2,3,400000000000,99");
+        for &compress in &[false, true] {
+            let binary = intcode.to_binary(compress);
+            let loaded = IntCode::from_bytes(&binary).unwrap();
+            assert_eq!(loaded.to_text(), intcode.to_text());
+        }
+    }
+
+    #[test]
+    fn format_errors() {
+        assert_eq!(
+            "1,2,x".parse::<IntCode>().unwrap_err(),
+            ParseIntCodeError::InvalidWord {
+                index: 2,
+                offset: 4,
+                text: "x".to_owned()
+            }
+        );
+
+        let intcode: IntCode = "1,2,3".parse().unwrap();
+        let mut binary = intcode.to_binary(false);
+        let last = binary.len() - 1;
+        binary[last] ^= 1;
+        assert!(matches!(
+            IntCode::from_bytes(&binary),
+            Err(ParseIntCodeError::Checksum { .. })
+        ));
+        assert_eq!(
+            IntCode::from_bytes(&binary[..6]).unwrap_err(),
+            ParseIntCodeError::Truncated { index: 0 }
+        );
+    }
+
+    #[test]
+    fn diff() {
+        let mut intcode: IntCode = "1,0,0,0,99".parse().unwrap();
+        let initial = intcode.clone();
+        intcode.execute().unwrap();
+        let diff = initial.diff(&intcode);
+        assert_eq!(diff.memory, vec![(0, (1, 2))]);
+        assert_eq!(diff.instruction_pointer, Some((0, 4)));
+        assert!(intcode.diff(&intcode).is_empty());
     }
 }
@@ -0,0 +1,89 @@
+use super::IntCode;
+use itertools::Itertools;
+use std::fmt;
+
+/// The differences between two `IntCode` states.  Each change is given as a pair (before, after).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Diff {
+    /// Changed memory, by address.
+    pub memory: Vec<(usize, (i64, i64))>,
+    /// A change in the instruction pointer.
+    pub instruction_pointer: Option<(usize, usize)>,
+    /// A change in the relative base.
+    pub relative_base: Option<(i64, i64)>,
+    /// A change in the pending input.
+    pub input: Option<(Vec<i64>, Vec<i64>)>,
+    /// A change in the pending output.
+    pub output: Option<(Vec<i64>, Vec<i64>)>,
+}
+
+impl Diff {
+    /// Whether the states were the same.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+fn changed<T: PartialEq>(before: T, after: T) -> Option<(T, T)> {
+    if before == after {
+        None
+    } else {
+        Some((before, after))
+    }
+}
+
+impl IntCode {
+    /// Compares our state with a later one.
+    pub fn diff(&self, after: &IntCode) -> Diff {
+        let size = self.memory.len().max(after.memory.len());
+        let memory = (0..size)
+            .filter_map(|address| {
+                changed(self.read(address), after.read(address)).map(|change| (address, change))
+            })
+            .collect();
+        Diff {
+            memory,
+            instruction_pointer: changed(self.instruction_pointer, after.instruction_pointer),
+            relative_base: changed(self.relative_base, after.relative_base),
+            input: changed(
+                self.input.iter().cloned().collect(),
+                after.input.iter().cloned().collect(),
+            ),
+            output: changed(
+                self.output.iter().cloned().collect(),
+                after.output.iter().cloned().collect(),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((before, after)) = self.instruction_pointer {
+            writeln!(fmt, "instruction pointer: {} -> {}", before, after)?;
+        }
+        if let Some((before, after)) = self.relative_base {
+            writeln!(fmt, "relative base: {} -> {}", before, after)?;
+        }
+        if let Some((before, after)) = &self.input {
+            writeln!(
+                fmt,
+                "input: [{}] -> [{}]",
+                before.iter().join(","),
+                after.iter().join(",")
+            )?;
+        }
+        if let Some((before, after)) = &self.output {
+            writeln!(
+                fmt,
+                "output: [{}] -> [{}]",
+                before.iter().join(","),
+                after.iter().join(",")
+            )?;
+        }
+        for (address, (before, after)) in &self.memory {
+            writeln!(fmt, "[{:>5}] {} -> {}", address, before, after)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,302 @@
+// Reading and writing Intcode programs.
+//
+// Programs are usually text: integers separated by commas and / or whitespace, with anything
+// from a '#' to the end of the line ignored.  There is also a compact binary encoding:
+//
+// - the four bytes "INTC" and a version byte
+// - the number of words, as a varint
+// - each word, zigzag encoded as a varint
+// - a CRC-32 of everything that precedes it, as four little-endian bytes
+//
+// Either form may be gzipped.
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::fmt;
+use std::io::{Read, Write};
+
+const MAGIC: &[u8] = b"INTC";
+const VERSION: u8 = 1;
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+
+/// Why a program could not be loaded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseIntCodeError {
+    /// A word of a text program is not an integer.
+    InvalidWord {
+        /// The position of the word in the program.
+        index: usize,
+        /// The byte offset at which the word starts.
+        offset: usize,
+        /// The word itself.
+        text: String,
+    },
+
+    /// The binary data ran out while reading a word.
+    Truncated {
+        /// The position of the word in the program.
+        index: usize,
+    },
+
+    /// A word of a binary program doesn't fit in an `i64`.
+    Overflow {
+        /// The position of the word in the program.
+        index: usize,
+    },
+
+    /// The checksum in a binary program doesn't match its contents.
+    Checksum {
+        /// The checksum in the program.
+        expected: u32,
+        /// The checksum of the contents.
+        actual: u32,
+    },
+
+    /// There's more binary data after the checksum.
+    TrailingData {
+        /// The number of extra bytes.
+        length: usize,
+    },
+
+    /// A binary program has a version that we don't understand.
+    UnsupportedVersion(u8),
+
+    /// The program is neither binary nor UTF-8 text.
+    InvalidText,
+
+    /// The program could not be decompressed.
+    Decompression(String),
+}
+
+impl fmt::Display for ParseIntCodeError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidWord {
+                index,
+                offset,
+                text,
+            } => write!(
+                fmt,
+                "word {} at byte {} is not an integer: {:?}",
+                index, offset, text
+            ),
+            Self::Truncated { index } => write!(fmt, "data ends in word {}", index),
+            Self::Overflow { index } => write!(fmt, "word {} is too large", index),
+            Self::Checksum { expected, actual } => write!(
+                fmt,
+                "checksum is {:08x}, but contents give {:08x}",
+                expected, actual
+            ),
+            Self::TrailingData { length } => {
+                write!(fmt, "{} unexpected bytes after checksum", length)
+            }
+            Self::UnsupportedVersion(version) => {
+                write!(fmt, "unsupported binary version {}", version)
+            }
+            Self::InvalidText => write!(fmt, "program is neither binary nor UTF-8 text"),
+            Self::Decompression(reason) => write!(fmt, "failed to decompress: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ParseIntCodeError {}
+
+// Parses a program from bytes in any of the supported forms.
+pub fn decode(bytes: &[u8]) -> Result<Vec<i64>, ParseIntCodeError> {
+    if bytes.starts_with(GZIP_MAGIC) {
+        let mut decompressed = vec![];
+        GzDecoder::new(bytes)
+            .read_to_end(&mut decompressed)
+            .map_err(|err| ParseIntCodeError::Decompression(err.to_string()))?;
+        decode(&decompressed)
+    } else if bytes.starts_with(MAGIC) {
+        decode_binary(bytes)
+    } else {
+        let text = std::str::from_utf8(bytes).map_err(|_| ParseIntCodeError::InvalidText)?;
+        decode_text(text)
+    }
+}
+
+pub fn decode_text(text: &str) -> Result<Vec<i64>, ParseIntCodeError> {
+    words(text)
+        .into_iter()
+        .enumerate()
+        .map(|(index, (offset, word))| {
+            word.parse().map_err(|_| ParseIntCodeError::InvalidWord {
+                index,
+                offset,
+                text: word.to_owned(),
+            })
+        })
+        .collect()
+}
+
+// Splits text into words, each with its byte offset.  Words are separated by a comma, by
+// whitespace, or by a comma with whitespace on either side: so "1,,2" has an empty word in the
+// middle but "1, 2" does not.
+fn words(text: &str) -> Vec<(usize, &str)> {
+    // Blank out comments, keeping everything else where it was.
+    let mut blanked = String::with_capacity(text.len());
+    for line in text.split_inclusive('\n') {
+        let (content, comment) = line.split_at(line.find('#').unwrap_or(line.len()));
+        blanked.push_str(content);
+        if comment.ends_with('\n') {
+            blanked.extend(std::iter::repeat_n(' ', comment.len() - 1));
+            blanked.push('\n');
+        } else {
+            blanked.extend(std::iter::repeat_n(' ', comment.len()));
+        }
+    }
+
+    let mut words = vec![];
+    if blanked.trim().is_empty() {
+        return words;
+    }
+    let start = blanked.len() - blanked.trim_start().len();
+    let mut offset = start;
+    for chunk in blanked.trim().split(',') {
+        let trimmed = chunk.trim();
+        if trimmed.is_empty() {
+            words.push((offset, ""));
+        }
+        for word in trimmed.split_whitespace() {
+            let word_offset = word.as_ptr() as usize - blanked.as_ptr() as usize;
+            words.push((word_offset, &text[word_offset..word_offset + word.len()]));
+        }
+        offset += chunk.len() + 1;
+    }
+    words
+}
+
+pub fn encode_text(memory: &[i64]) -> String {
+    memory
+        .iter()
+        .map(|word| word.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+pub fn decode_binary(bytes: &[u8]) -> Result<Vec<i64>, ParseIntCodeError> {
+    let version = bytes.get(MAGIC.len()).cloned();
+    match version {
+        Some(VERSION) => {}
+        Some(version) => return Err(ParseIntCodeError::UnsupportedVersion(version)),
+        None => return Err(ParseIntCodeError::Truncated { index: 0 }),
+    }
+
+    let mut reader = Reader::new(&bytes[MAGIC.len() + 1..]);
+    let count = reader
+        .varint()
+        .ok_or(ParseIntCodeError::Truncated { index: 0 })?;
+    let mut memory = vec![];
+    for index in 0..count as usize {
+        let value = match reader.varint() {
+            Some(value) => value,
+            None if reader.overflowed => return Err(ParseIntCodeError::Overflow { index }),
+            None => return Err(ParseIntCodeError::Truncated { index }),
+        };
+        memory.push(unzigzag(value));
+    }
+
+    let contents = bytes.len() - reader.remaining.len();
+    let checksum = reader.checksum().ok_or(ParseIntCodeError::Truncated {
+        index: memory.len(),
+    })?;
+    let actual = crc32fast::hash(&bytes[..contents]);
+    if checksum != actual {
+        return Err(ParseIntCodeError::Checksum {
+            expected: checksum,
+            actual,
+        });
+    }
+    if !reader.remaining.is_empty() {
+        return Err(ParseIntCodeError::TrailingData {
+            length: reader.remaining.len(),
+        });
+    }
+
+    Ok(memory)
+}
+
+pub fn encode_binary(memory: &[i64]) -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.push(VERSION);
+    push_varint(&mut bytes, memory.len() as u64);
+    for &word in memory {
+        push_varint(&mut bytes, zigzag(word));
+    }
+    let checksum = crc32fast::hash(&bytes);
+    bytes.extend_from_slice(&checksum.to_le_bytes());
+    bytes
+}
+
+pub fn compress(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = GzEncoder::new(vec![], Compression::best());
+    encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .expect("Writing to memory failed")
+}
+
+// Maps signed integers to unsigned, so that small magnitudes give small numbers:
+// 0 -> 0, -1 -> 1, 1 -> 2, -2 -> 3, ...
+fn zigzag(n: i64) -> u64 {
+    ((n << 1) ^ (n >> 63)) as u64
+}
+
+fn unzigzag(n: u64) -> i64 {
+    ((n >> 1) as i64) ^ -((n & 1) as i64)
+}
+
+// Little-endian base 128: seven bits per byte, with the top bit set on all but the last byte.
+fn push_varint(bytes: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        bytes.push((n as u8) | 0x80);
+        n >>= 7;
+    }
+    bytes.push(n as u8);
+}
+
+struct Reader<'a> {
+    remaining: &'a [u8],
+    overflowed: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self {
+            remaining: bytes,
+            overflowed: false,
+        }
+    }
+
+    fn varint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+        let mut shift = 0;
+        loop {
+            let (&byte, rest) = self.remaining.split_first()?;
+            self.remaining = rest;
+            let bits = u64::from(byte & 0x7f);
+            if shift >= 64 || (shift == 63 && bits > 1) {
+                self.overflowed = true;
+                return None;
+            }
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+            shift += 7;
+        }
+    }
+
+    fn checksum(&mut self) -> Option<u32> {
+        if self.remaining.len() < 4 {
+            return None;
+        }
+        let (checksum, rest) = self.remaining.split_at(4);
+        self.remaining = rest;
+        let mut word = [0; 4];
+        word.copy_from_slice(checksum);
+        Some(u32::from_le_bytes(word))
+    }
+}
@@ -0,0 +1,23 @@
+//! Solutions to the Advent of Code.
+//!
+//! Each year has a module, such as [`year2019`], containing a module for each day's puzzle.  Those
+//! expose a type that implements [`solution::Solution`]: parse the puzzle input, which fails with
+//! an [`error::Error`] if it isn't valid, and then ask for the answer to either part.  The
+//! [`solution::YEARS`] registry lists them all.  The Intcode computer that many of the 2019
+//! puzzles use is in [`intcode`], maps of points on a grid are in [`grid`], and searches for
+//! shortest paths are in [`search`].
+#![warn(missing_docs)]
+
+#[macro_use]
+extern crate itertools;
+
+pub mod answers;
+pub mod error;
+pub mod grid;
+pub mod intcode;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod util;
+pub mod year2019;
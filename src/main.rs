@@ -1,68 +1,490 @@
-#[macro_use]
-extern crate itertools;
-#[macro_use]
-extern crate lazy_static;
+use advent_of_code_2019::answers::Answers;
+use advent_of_code_2019::grid::Direction;
+use advent_of_code_2019::intcode::{ExecuteResult, IntCode};
+use advent_of_code_2019::runner::{self, Format, Selection};
+use advent_of_code_2019::scaffold;
+use advent_of_code_2019::solution::{self, Solution, Year};
+use advent_of_code_2019::year2019::day18::Day18;
+use rayon::prelude::*;
+use std::fmt::Display;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use structopt::clap::{Error, ErrorKind};
 use structopt::StructOpt;
-mod day01;
-mod day02;
-mod day03;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-mod intcode;
-mod util;
 
 #[derive(StructOpt)]
+#[structopt(after_help = "EXIT STATUS:
+    0    Success
+    1    A puzzle input was invalid, or a file couldn't be read
+    2    Invalid arguments, or no solution for the selected days
+    3    Checking found wrong answers")]
 struct Cli {
-    day: u8,
+    /// Day of the puzzle to solve, a range of days such as 1..=10, or "all"
+    days: Option<Selection>,
+
+    /// Year of the puzzles [default: the latest year]
+    #[structopt(long)]
+    year: Option<u16>,
+
+    /// Read the puzzle input from this file, or from stdin if "-" [default: YYYY/dayNN.txt in
+    /// the data directory]
+    #[structopt(long, parse(from_os_str))]
+    input: Option<PathBuf>,
+
+    /// Directory containing the puzzle inputs
+    #[structopt(long, parse(from_os_str), default_value = "data")]
+    data_dir: PathBuf,
+
+    /// When running several days, run them concurrently
+    #[structopt(long)]
+    parallel: bool,
+
+    /// Run only this part of each puzzle
+    #[structopt(long, possible_values = &["1", "2"])]
+    part: Option<usize>,
+
+    /// How to print the answers: "text" or "json"
+    #[structopt(long, default_value = "text")]
+    format: Format,
+
+    /// Check the answers against those in the answers file, instead of printing them (only as
+    /// text)
+    #[structopt(long)]
+    check: bool,
+
+    /// File of expected answers [default: YYYY/answers.txt in the data directory]
+    #[structopt(long, parse(from_os_str))]
+    answers: Option<PathBuf>,
+
+    #[structopt(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(StructOpt)]
+enum Command {
+    /// Runs an Intcode program until it halts or needs input, then shows which instruction last
+    /// wrote to an address
+    Blame {
+        /// Program to run, as text or binary
+        #[structopt(parse(from_os_str))]
+        program: PathBuf,
+
+        /// Address to look into
+        address: usize,
+
+        /// Provide a value as input
+        #[structopt(short, long, number_of_values = 1, allow_hyphen_values = true)]
+        input: Vec<i64>,
+
+        /// Step back through this many instructions before looking
+        #[structopt(long, default_value = "0")]
+        back: usize,
+    },
+
+    /// Converts an Intcode program between its text and binary forms
+    Convert {
+        /// Program to convert, as text or binary
+        #[structopt(parse(from_os_str))]
+        program: PathBuf,
+
+        /// File to write the converted program to
+        #[structopt(parse(from_os_str))]
+        output: PathBuf,
+
+        /// Write the binary form, rather than text
+        #[structopt(long)]
+        binary: bool,
+
+        /// Compress the binary form with gzip
+        #[structopt(long, requires = "binary")]
+        gzip: bool,
+    },
+
+    /// Runs an Intcode program until it halts, needs input or reaches a breakpoint, then shows
+    /// how its state differs from the initial image
+    Diff {
+        /// Program to run, as text or binary
+        #[structopt(parse(from_os_str))]
+        program: PathBuf,
+
+        /// Stop when the instruction pointer reaches this address
+        #[structopt(short, long = "break", number_of_values = 1)]
+        breakpoints: Vec<usize>,
+
+        /// Write a value to memory before starting, as ADDRESS=VALUE
+        #[structopt(long, number_of_values = 1, parse(try_from_str = parse_poke))]
+        poke: Vec<(usize, i64)>,
+
+        /// Provide a value as input
+        #[structopt(short, long, number_of_values = 1, allow_hyphen_values = true)]
+        input: Vec<i64>,
+
+        /// Provide a line of text as ASCII input
+        #[structopt(long, number_of_values = 1)]
+        ascii: Vec<String>,
+    },
+
+    /// Plays one of the interactive puzzles
+    Play {
+        /// Day of the puzzle to play
+        #[structopt(parse(try_from_str = parse_day))]
+        day: u8,
+    },
+
+    /// Shows the order in which the keys of day 18 of 2019 are collected, and each step taken to
+    /// collect them
+    Keys {
+        /// Split a single entrance between four robots, as in the second part of the puzzle
+        #[structopt(long)]
+        robots: bool,
+
+        /// Draw the route over the map
+        #[structopt(long)]
+        map: bool,
+    },
+
+    /// Creates a skeleton solution for a new day, and registers it
+    Scaffold {
+        /// Day of the puzzle
+        #[structopt(parse(try_from_str = parse_day))]
+        day: u8,
+
+        /// Directory containing the source code
+        #[structopt(long, parse(from_os_str), default_value = "src")]
+        src_dir: PathBuf,
+    },
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("expected a day from 1 to 25, got {}", s)),
+    }
 }
 
+fn parse_poke(s: &str) -> Result<(usize, i64), String> {
+    let mut parts = s.splitn(2, '=');
+    let address = parts.next().and_then(|a| a.parse().ok());
+    let value = parts.next().and_then(|v| v.parse().ok());
+    match (address, value) {
+        (Some(address), Some(value)) => Ok((address, value)),
+        _ => Err(format!("expected ADDRESS=VALUE, got {}", s)),
+    }
+}
+
+// Exit statuses, so that scripts can tell what went wrong.
+const FAILED: i32 = 1; // A puzzle input was invalid, or a file couldn't be read.
+const USAGE: i32 = 2; // The arguments were invalid, or asked for a day we have no solution for.
+const WRONG: i32 = 3; // Checking found wrong answers.
+
 fn main() {
-    let args = Cli::from_args();
-    match args.day {
-        1 => day01::day01(),
-        2 => day02::day02(),
-        3 => day03::day03(),
-        4 => println!("Implemented day four in J!"),
-        5 => day05::day05(),
-        6 => day06::day06(),
-        7 => day07::day07(),
-        8 => day08::day08(),
-        9 => day09::day09(),
-        10 => day10::day10(),
-        11 => day11::day11(),
-        12 => day12::day12(),
-        13 => day13::day13(),
-        14 => day14::day14(),
-        15 => day15::day15(),
-        16 => day16::day16(),
-        17 => day17::day17(),
-        18 => day18::day18(),
-        19 => day19::day19(),
-        20 => day20::day20(),
-        21 => day21::day21(),
-        22 => day22::day22(),
-        23 => day23::day23(),
-        24 => day24::day24(),
-        25 => day25::day25(),
-        _ => println!("Unimplemented day: {}", args.day),
+    let args = Cli::from_iter_safe(std::env::args_os()).unwrap_or_else(|err| match err.kind {
+        ErrorKind::HelpDisplayed | ErrorKind::VersionDisplayed => err.exit(),
+        _ => usage(err),
+    });
+    let year = args
+        .year
+        .unwrap_or_else(|| solution::YEARS.last().expect("No years").year);
+    let data_dir = args.data_dir;
+    let year_dir = data_dir.join(year.to_string());
+    let input_path = args.input;
+    let load = |day: u8| {
+        let path = input_path
+            .clone()
+            .unwrap_or_else(|| year_dir.join(format!("day{:02}.txt", day)));
+        read_input(&path)
+    };
+
+    if let Some(command) = args.command {
+        match command {
+            Command::Blame {
+                program,
+                address,
+                input,
+                back,
+            } => blame(&program, address, &input, back),
+            Command::Convert {
+                program,
+                output,
+                binary,
+                gzip,
+            } => convert(&program, &output, binary, gzip),
+            Command::Diff {
+                program,
+                breakpoints,
+                poke,
+                input,
+                ascii,
+            } => diff(&program, &breakpoints, &poke, &input, &ascii),
+            Command::Play { day } => {
+                let year_entry = find_year(year);
+                match year_entry.find(day).and_then(|entry| entry.play) {
+                    Some(play) => {
+                        play(&load(day)).unwrap_or_else(|err| fail(err.on_day(year, day)))
+                    }
+                    None => {
+                        let interactive =
+                            year_entry.days.iter().filter(|entry| entry.play.is_some());
+                        let days = interactive.map(|entry| entry.day).collect::<Vec<_>>();
+                        let message = format!(
+                            "Day {} of {} is not interactive (interactive days: {})",
+                            day,
+                            year,
+                            runner::describe_days(&days)
+                        );
+                        usage(Error::with_description(&message, ErrorKind::InvalidValue));
+                    }
+                }
+            }
+            Command::Keys { robots, map } => {
+                if args.year.is_some_and(|year| year != 2019) {
+                    usage(Error::with_description(
+                        "The keys command only works on day 18 of 2019",
+                        ErrorKind::ArgumentConflict,
+                    ));
+                }
+                let path = input_path.unwrap_or_else(|| data_dir.join("2019").join("day18.txt"));
+                keys(&read_input(&path), robots, map)
+            }
+            Command::Scaffold { day, src_dir } => {
+                if let Err(err) = scaffold::scaffold(&src_dir, &data_dir, year, day) {
+                    fail(format!("could not create day {} of {}: {}", day, year, err));
+                }
+                println!("Created day {} of {}", day, year);
+            }
+        }
+        return;
+    }
+
+    let year_entry = find_year(year);
+    let days = match args.days {
+        Some(days) => days,
+        None if args.check => Selection::all(),
+        None => usage(Error::with_description(
+            "No day given",
+            ErrorKind::MissingRequiredArgument,
+        )),
+    };
+    if args.check && args.format == Format::Json {
+        usage(Error::with_description(
+            "--check can't be used with --format json",
+            ErrorKind::ArgumentConflict,
+        ));
+    }
+    if days.single().is_none() && input_path.is_some() {
+        usage(Error::with_description(
+            "--input can only be used with a single day",
+            ErrorKind::ArgumentConflict,
+        ));
+    }
+    let entries = days.days(year_entry).collect::<Vec<_>>();
+    if entries.is_empty() {
+        let available = year_entry.days.iter().map(|entry| entry.day);
+        let available = available.collect::<Vec<_>>();
+        let selected = match days.single() {
+            Some(day) => format!("day {}", day),
+            None => "the selected days".to_owned(),
+        };
+        let message = format!(
+            "No solution for {} of {} (available days: {})",
+            selected,
+            year,
+            runner::describe_days(&available)
+        );
+        usage(Error::with_description(&message, ErrorKind::InvalidValue));
+    }
+
+    let part = args.part;
+    let start = Instant::now();
+    let reports = if args.parallel {
+        entries
+            .par_iter()
+            .map(|entry| runner::run(year, entry, &load(entry.day), part))
+            .collect::<Result<Vec<_>, _>>()
+    } else {
+        entries
+            .iter()
+            .map(|entry| runner::run(year, entry, &load(entry.day), part))
+            .collect()
+    };
+    let wall_time = start.elapsed();
+    let reports = reports.unwrap_or_else(|err| fail(err));
+
+    if args.check {
+        let path = args.answers.unwrap_or_else(|| year_dir.join("answers.txt"));
+        let answers: Answers = read_input(&path)
+            .parse()
+            .unwrap_or_else(|err| fail(format!("could not read {}: {}", path.display(), err)));
+        let mismatches = answers.check(&reports);
+        for mismatch in &mismatches {
+            print!("{}", mismatch);
+        }
+        let checked = reports
+            .iter()
+            .map(|report| report.parts.len())
+            .sum::<usize>();
+        println!("Checked {} answers: {} wrong", checked, mismatches.len());
+        if !mismatches.is_empty() {
+            std::process::exit(WRONG);
+        }
+    } else if args.format == Format::Json {
+        runner::print_json(&reports);
+    } else if days.single().is_some() {
+        runner::print_answers(&reports[0]);
+    } else {
+        for report in &reports {
+            println!("Day {}", report.day);
+            runner::print_answers(report);
+            println!();
+        }
+        runner::print_timings(&reports);
+        println!("Wall time (ms): {}", runner::millis(wall_time));
+    }
+}
+
+// Reports an error concisely, rather than panicking with a backtrace, and exits.
+fn fail(message: impl Display) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(FAILED);
+}
+
+// Reports invalid arguments as structopt does, but with our own exit status.
+fn usage(err: Error) -> ! {
+    eprintln!("{}", err.message);
+    std::process::exit(USAGE);
+}
+
+fn find_year(year: u16) -> &'static Year {
+    solution::find_year(year).unwrap_or_else(|| {
+        let years = solution::YEARS.iter().map(|entry| entry.year.to_string());
+        let message = format!(
+            "No solutions for {} (available years: {})",
+            year,
+            years.collect::<Vec<_>>().join(", ")
+        );
+        usage(Error::with_description(&message, ErrorKind::InvalidValue))
+    })
+}
+
+fn read_input(path: &Path) -> String {
+    let input = if path == Path::new("-") {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    input.unwrap_or_else(|err| fail(format!("could not read {}: {}", path.display(), err)))
+}
+
+fn blame(path: &Path, address: usize, input: &[i64], back: usize) {
+    let bytes = std::fs::read(path)
+        .unwrap_or_else(|err| fail(format!("could not read {}: {}", path.display(), err)));
+    let mut intcode = IntCode::from_bytes(&bytes)
+        .unwrap_or_else(|err| fail(format!("could not parse {}: {}", path.display(), err)));
+    intcode.record_history();
+    for &value in input {
+        intcode.add_input(value);
+    }
+    if let Err(err) = intcode.execute() {
+        println!("Program failed: {}", err);
+    }
+    for _ in 0..back {
+        if !intcode.step_back() {
+            break;
+        }
+    }
+    println!("Stopped after {} steps", intcode.steps_recorded());
+    let value = intcode.read(address);
+    match intcode.last_write(address) {
+        Some(write) => println!(
+            "Address {} holds {}, written at step {} by the instruction at {}, replacing {}",
+            address, value, write.step, write.instruction_pointer, write.previous
+        ),
+        None => println!(
+            "Address {} holds {}, as it did at the start",
+            address, value
+        ),
+    }
+}
+
+fn convert(path: &Path, output: &Path, binary: bool, gzip: bool) {
+    let bytes = std::fs::read(path)
+        .unwrap_or_else(|err| fail(format!("could not read {}: {}", path.display(), err)));
+    let intcode = IntCode::from_bytes(&bytes)
+        .unwrap_or_else(|err| fail(format!("could not parse {}: {}", path.display(), err)));
+    let converted = if binary {
+        intcode.to_binary(gzip)
+    } else {
+        intcode.to_text().into_bytes()
+    };
+    std::fs::write(output, converted)
+        .unwrap_or_else(|err| fail(format!("could not write {}: {}", output.display(), err)));
+}
+
+fn keys(input: &str, robots: bool, map: bool) {
+    let vault = Day18::parse(input).unwrap_or_else(|err| fail(err.on_day(2019, 18)));
+    let route = vault
+        .route(robots)
+        .unwrap_or_else(|err| fail(err.on_day(2019, 18)));
+    let order = route.keys.iter().map(|&(_, key)| key).collect::<String>();
+    println!("Collected {} in {} steps", order, route.steps);
+    for (robot, directions) in route.directions().iter().enumerate() {
+        let keys = route.keys.iter().filter(|&&(by, _)| by == robot);
+        let keys = keys.map(|&(_, key)| key).collect::<String>();
+        let steps = directions.iter().map(|direction| match direction {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        });
+        println!(
+            "Robot {} collects {} in {} steps:",
+            robot + 1,
+            keys,
+            directions.len()
+        );
+        println!("{}", steps.collect::<String>());
+    }
+    if map {
+        println!("{}", route.render());
+    }
+}
+
+fn diff(
+    path: &Path,
+    breakpoints: &[usize],
+    pokes: &[(usize, i64)],
+    input: &[i64],
+    ascii: &[String],
+) {
+    let bytes = std::fs::read(path)
+        .unwrap_or_else(|err| fail(format!("could not read {}: {}", path.display(), err)));
+    let mut intcode = IntCode::from_bytes(&bytes)
+        .unwrap_or_else(|err| fail(format!("could not parse {}: {}", path.display(), err)));
+    let initial = intcode.clone();
+    for &(address, value) in pokes {
+        intcode.write(address, value);
+    }
+    for &value in input {
+        intcode.add_input(value);
+    }
+    for line in ascii {
+        for c in line.chars() {
+            intcode.add_input(c as u8 as i64);
+        }
+        intcode.add_input(b'\n' as i64);
+    }
+    match intcode.execute_until(breakpoints) {
+        Ok(Some(ExecuteResult::Done)) => println!("Program halted"),
+        Ok(Some(ExecuteResult::InputNeeded)) => println!("Program needs input"),
+        Ok(None) => println!("Reached breakpoint"),
+        Err(err) => println!("Program failed: {}", err),
+    }
+    let diff = initial.diff(&intcode);
+    if diff.is_empty() {
+        println!("No changes");
+    } else {
+        print!("{}", diff);
     }
 }
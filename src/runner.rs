@@ -0,0 +1,337 @@
+//! Running several days at once, and reporting how long they took.
+use crate::error::Error;
+use crate::solution::{Answer, Day, Solution, Year};
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Which days to run: a single day, a range such as "1..=10" or "5..8", or "all".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Selection {
+    first: u8,
+    last: u8,
+}
+
+impl Selection {
+    /// Every day.
+    pub fn all() -> Self {
+        Self { first: 1, last: 25 }
+    }
+
+    /// The selected day, if just one is selected.
+    pub fn single(&self) -> Option<u8> {
+        if self.first == self.last {
+            Some(self.first)
+        } else {
+            None
+        }
+    }
+
+    /// The selected days of a year that we have solutions for.
+    pub fn days(&self, year: &'static Year) -> impl Iterator<Item = &'static Day> + '_ {
+        year.days
+            .iter()
+            .filter(move |entry| self.first <= entry.day && entry.day <= self.last)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned when a `Selection` can't be parsed.
+pub struct ParseSelectionError;
+
+impl fmt::Display for ParseSelectionError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            fmt,
+            "expected a day from 1 to 25, a range such as 1..=10, or \"all\""
+        )
+    }
+}
+
+impl FromStr for Selection {
+    type Err = ParseSelectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| day.parse::<u8>().map_err(|_| ParseSelectionError);
+        if s == "all" {
+            return Ok(Self::all());
+        }
+        let (first, last) = if let Some((first, last)) = s.split_once("..=") {
+            (parse_day(first)?, parse_day(last)?)
+        } else if let Some((first, last)) = s.split_once("..") {
+            let last = parse_day(last)?.checked_sub(1).ok_or(ParseSelectionError)?;
+            (parse_day(first)?, last)
+        } else {
+            let day = parse_day(s)?;
+            (day, day)
+        };
+        if first < 1 || first > last || last > 25 {
+            return Err(ParseSelectionError);
+        }
+        Ok(Self { first, last })
+    }
+}
+
+/// Lists days compactly, such as "1-3, 5, 7-9", for messages.  The days must be in order.
+pub fn describe_days(days: &[u8]) -> String {
+    let mut runs: Vec<(u8, u8)> = vec![];
+    for &day in days {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == day => *last = day,
+            _ => runs.push((day, day)),
+        }
+    }
+    let runs = runs.iter().map(|&(first, last)| {
+        if first == last {
+            first.to_string()
+        } else {
+            format!("{}-{}", first, last)
+        }
+    });
+    runs.collect::<Vec<_>>().join(", ")
+}
+
+/// How to print the answers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Text for people to read.
+    Text,
+    /// JSON for programs to read.
+    Json,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+/// The error returned when a `Format` can't be parsed.
+pub struct ParseFormatError;
+
+impl fmt::Display for ParseFormatError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "expected \"text\" or \"json\"")
+    }
+}
+
+impl FromStr for Format {
+    type Err = ParseFormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(ParseFormatError),
+        }
+    }
+}
+
+/// The answers to one day's puzzle, and how long it took to get them.
+pub struct Report {
+    /// The year of the puzzle.
+    pub year: u16,
+    /// The day of the puzzle.
+    pub day: u8,
+    /// How long it took to parse the input.
+    pub parse: Duration,
+    /// The parts that we ran.
+    pub parts: Vec<PartReport>,
+}
+
+/// The answer to one part of a puzzle, and how long it took to get it.
+pub struct PartReport {
+    /// Which part: 1 or 2.
+    pub part: usize,
+    /// The answer.
+    pub answer: Answer,
+    /// How long it took.
+    pub time: Duration,
+}
+
+impl Report {
+    fn total(&self) -> Duration {
+        self.parse + self.parts.iter().map(|part| part.time).sum::<Duration>()
+    }
+
+    fn time(&self, part: usize) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|report| report.part == part)
+            .map(|report| report.time)
+    }
+}
+
+type PartFn = fn(&dyn Solution) -> Result<Answer, Error>;
+
+/// Runs the given part of a day's puzzle, or both parts if none is given.
+///
+/// # Errors
+///
+/// Fails if the input is not valid for the puzzle, or has no answer to a part.
+pub fn run(year: u16, entry: &Day, input: &str, part: Option<usize>) -> Result<Report, Error> {
+    let start = Instant::now();
+    let solution = (entry.parse)(input).map_err(|err| err.on_day(year, entry.day))?;
+    let parse = start.elapsed();
+
+    let mut parts = vec![];
+    let part_fns: [PartFn; 2] = [
+        |solution| solution.part_one(),
+        |solution| solution.part_two(),
+    ];
+    for (index, part_fn) in part_fns.iter().enumerate() {
+        if part.is_some_and(|part| part != index + 1) {
+            continue;
+        }
+        let start = Instant::now();
+        let answer = part_fn(&*solution).map_err(|err| err.on_day(year, entry.day))?;
+        parts.push(PartReport {
+            part: index + 1,
+            answer,
+            time: start.elapsed(),
+        });
+    }
+
+    Ok(Report {
+        year,
+        day: entry.day,
+        parse,
+        parts,
+    })
+}
+
+/// Prints the answers in a report.  Pictures go on the lines following the heading.
+pub fn print_answers(report: &Report) {
+    for PartReport { part, answer, .. } in &report.parts {
+        let part = if *part == 1 { "one" } else { "two" };
+        match answer {
+            Answer::Image(_) => println!("Part {} answer is:\n{}", part, answer),
+            _ => println!("Part {} answer is: {}", part, answer),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DayJson<'a> {
+    year: u16,
+    day: u8,
+    parse_ms: f64,
+    parts: Vec<PartJson<'a>>,
+    total_ms: f64,
+}
+
+#[derive(Serialize)]
+struct PartJson<'a> {
+    part: usize,
+    answer: &'a Answer,
+    time_ms: f64,
+}
+
+fn as_millis(time: Duration) -> f64 {
+    time.as_secs_f64() * 1000.0
+}
+
+/// Prints reports as a JSON array.
+pub fn print_json(reports: &[Report]) {
+    let days = reports
+        .iter()
+        .map(|report| DayJson {
+            year: report.year,
+            day: report.day,
+            parse_ms: as_millis(report.parse),
+            parts: report
+                .parts
+                .iter()
+                .map(|part| PartJson {
+                    part: part.part,
+                    answer: &part.answer,
+                    time_ms: as_millis(part.time),
+                })
+                .collect(),
+            total_ms: as_millis(report.total()),
+        })
+        .collect::<Vec<_>>();
+    let json = serde_json::to_string_pretty(&days).expect("Failed to write JSON");
+    println!("{}", json);
+}
+
+/// Formats a duration in milliseconds.
+pub fn millis(time: Duration) -> String {
+    format!("{:.3}", as_millis(time))
+}
+
+// Parts that weren't run are shown as "-".
+fn optional_millis(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_owned(), millis)
+}
+
+/// Prints a table of how long each day took, with totals.
+pub fn print_timings(reports: &[Report]) {
+    println!(
+        "{:>4} {:>12} {:>12} {:>12} {:>12}",
+        "Day", "Parse (ms)", "Part 1 (ms)", "Part 2 (ms)", "Total (ms)"
+    );
+    for report in reports {
+        println!(
+            "{:>4} {:>12} {:>12} {:>12} {:>12}",
+            report.day,
+            millis(report.parse),
+            optional_millis(report.time(1)),
+            optional_millis(report.time(2)),
+            millis(report.total())
+        );
+    }
+
+    let parse = reports.iter().map(|report| report.parse).sum();
+    let part = |part| -> Option<Duration> {
+        let times = reports.iter().filter_map(|report| report.time(part));
+        times.fold(None, |total, time| Some(total.unwrap_or_default() + time))
+    };
+    let total = reports.iter().map(Report::total).sum();
+    println!(
+        "{:>4} {:>12} {:>12} {:>12} {:>12}",
+        "All",
+        millis(parse),
+        optional_millis(part(1)),
+        optional_millis(part(2)),
+        millis(total)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Option<(u8, u8)> {
+        s.parse::<Selection>()
+            .ok()
+            .map(|selection| (selection.first, selection.last))
+    }
+
+    #[test]
+    fn selections() {
+        assert_eq!(parse("7"), Some((7, 7)));
+        assert_eq!(parse("1..=10"), Some((1, 10)));
+        assert_eq!(parse("1..10"), Some((1, 9)));
+        assert_eq!(parse("all"), Some((1, 25)));
+        assert_eq!(parse("5..=3"), None);
+        assert_eq!(parse("1..1"), None);
+        assert_eq!(parse("x"), None);
+        assert_eq!(parse("0"), None);
+        assert_eq!(parse("30"), None);
+        assert_eq!(parse("20..=26"), None);
+        assert_eq!(parse("20..26"), Some((20, 25)));
+    }
+
+    #[test]
+    fn descriptions() {
+        assert_eq!(describe_days(&[1, 2, 3, 5, 7, 8, 9]), "1-3, 5, 7-9");
+        assert_eq!(describe_days(&[4]), "4");
+        assert_eq!(describe_days(&[]), "");
+    }
+
+    #[test]
+    fn selected_days() {
+        let selection: Selection = "3..=5".parse().unwrap();
+        let year = crate::solution::find_year(2019).unwrap();
+        let days = selection.days(year).map(|entry| entry.day);
+        let days = days.collect::<Vec<_>>();
+        assert_eq!(days, [3, 4, 5]);
+    }
+}
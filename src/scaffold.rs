@@ -0,0 +1,204 @@
+//! Generating the skeleton of a new day's solution.
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// A new day reports an error until it is solved, rather than panicking: a new year becomes the
+// default, so its stubs are what a plain run of a day reaches.
+fn template(day: u8) -> String {
+    format!(
+        r#"//! Day {day}.
+use crate::error::Error;
+use crate::solution::{{Answer, Solution}};
+
+/// Day {day}'s puzzle.
+pub struct Day{day:02};
+
+impl Solution for Day{day:02} {{
+    fn parse(_input: &str) -> Result<Self, Error> {{
+        Ok(Self)
+    }}
+
+    fn part_one(&self) -> Result<Answer, Error> {{
+        Err(Error::new("not solved yet"))
+    }}
+
+    fn part_two(&self) -> Result<Answer, Error> {{
+        Err(Error::new("not solved yet"))
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = "";
+
+    #[test]
+    #[ignore]
+    fn example() {{
+        let day = Day{day:02}::parse(EXAMPLE).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(0));
+    }}
+}}
+"#,
+        day = day
+    )
+}
+
+// Adds a line to a sorted run of similar lines: those that start with `prefix`.
+fn insert_sorted(text: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|l| l.starts_with(prefix))?;
+    let count = lines[first..]
+        .iter()
+        .take_while(|l| l.starts_with(prefix))
+        .count();
+    let run = &lines[first..first + count];
+    if run.contains(&line) {
+        return None;
+    }
+    let offset = run
+        .iter()
+        .position(|&existing| existing > line)
+        .unwrap_or(count);
+    lines.insert(first + offset, line);
+    Some(lines.join("\n") + "\n")
+}
+
+fn update(path: &Path, prefix: &str, line: &str) -> io::Result<()> {
+    let text = fs::read_to_string(path)?;
+    let updated = insert_sorted(&text, prefix, line).ok_or_else(|| {
+        let reason = format!("could not register in {}", path.display());
+        io::Error::new(io::ErrorKind::InvalidData, reason)
+    })?;
+    fs::write(path, updated)
+}
+
+// Adds an entry to a registry whose entries are lines like `Day::new(4, ...),`.  They are ordered
+// by number, not as text.  An empty registry gets its first entry before the closing `];`.
+fn register(text: &str, constructor: &str, number: u16, entry: &str) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    let is_entry = |line: &&str| line.trim_start().starts_with(constructor);
+    let before = lines.iter().position(|line| {
+        line.trim_start()
+            .strip_prefix(constructor)
+            .and_then(|rest| rest.split(',').next())
+            .and_then(|existing| existing.parse::<u16>().ok())
+            .is_some_and(|existing| existing > number)
+    });
+    let after_last = lines.iter().rposition(is_entry).map(|last| last + 1);
+    let position = before
+        .or(after_last)
+        .or_else(|| lines.iter().position(|line| *line == "];"))?;
+    lines.insert(position, entry);
+    Some(lines.join("\n") + "\n")
+}
+
+fn year_template(year: u16) -> String {
+    format!(
+        r#"//! Solutions to the {year} puzzles.
+use crate::solution::{{parse, Day}};
+
+/// Every day of {year} that we have a solution for, in order.
+pub static DAYS: &[Day] = &[
+    // Entries are added by the scaffold command.
+];
+"#,
+        year = year
+    )
+}
+
+fn invalid(path: &Path) -> io::Error {
+    let reason = format!("no registry in {}", path.display());
+    io::Error::new(io::ErrorKind::InvalidData, reason)
+}
+
+/// Creates `yearYYYY/dayNN.rs` in the source directory, registers it in `yearYYYY.rs`, and
+/// creates an empty input file in the year's data directory.  A new year gets its own module,
+/// registered in `lib.rs` and `solution.rs`.
+///
+/// Fails without changing anything if the module already exists.
+pub fn scaffold(src_dir: &Path, data_dir: &Path, year: u16, day: u8) -> io::Result<()> {
+    let year_dir = src_dir.join(format!("year{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        let reason = format!("{} already exists", module.display());
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, reason));
+    }
+
+    let year_module = src_dir.join(format!("year{}.rs", year));
+    let new_year = !year_module.exists();
+    if new_year {
+        let registry = src_dir.join("solution.rs");
+        let text = fs::read_to_string(&registry)?;
+        let entry = format!("    Year::new({}, crate::year{}::DAYS),", year, year);
+        let registered =
+            register(&text, "Year::new(", year, &entry).ok_or_else(|| invalid(&registry))?;
+        update(
+            &src_dir.join("lib.rs"),
+            "pub mod year",
+            &format!("pub mod year{};", year),
+        )?;
+        fs::write(&registry, registered)?;
+        fs::write(&year_module, year_template(year))?;
+    }
+
+    let text = fs::read_to_string(&year_module)?;
+    let entry = format!(
+        "    Day::new({}, parse::<day{:02}::Day{:02}>),",
+        day, day, day
+    );
+    let registered =
+        register(&text, "Day::new(", day.into(), &entry).ok_or_else(|| invalid(&year_module))?;
+    let module_line = format!("pub mod day{:02};", day);
+    let registered = insert_sorted(&registered, "pub mod day", &module_line)
+        .unwrap_or_else(|| registered.replacen("\n\n", &format!("\n\n{}\n\n", module_line), 1));
+    fs::write(&year_module, registered)?;
+    fs::create_dir_all(&year_dir)?;
+    fs::write(&module, template(day))?;
+
+    let data_dir = data_dir.join(year.to_string());
+    fs::create_dir_all(&data_dir)?;
+    let input = data_dir.join(format!("day{:02}.txt", day));
+    if !input.exists() {
+        fs::write(input, "")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert() {
+        let text = "mod a;\npub mod day01;\npub mod day03;\npub mod intcode;\n";
+        let updated = insert_sorted(text, "pub mod day", "pub mod day02;").unwrap();
+        assert_eq!(
+            updated,
+            "mod a;\npub mod day01;\npub mod day02;\npub mod day03;\npub mod intcode;\n"
+        );
+        assert_eq!(insert_sorted(text, "pub mod day", "pub mod day03;"), None);
+        assert_eq!(
+            insert_sorted(text, "pub mod year", "pub mod year2019;"),
+            None
+        );
+    }
+
+    #[test]
+    fn registry() {
+        let text = "&[\n    Day::new(2, a),\n    Day::new(10, b),\n];\n";
+        let updated = register(text, "Day::new(", 9, "    Day::new(9, c),").unwrap();
+        assert_eq!(
+            updated,
+            "&[\n    Day::new(2, a),\n    Day::new(9, c),\n    Day::new(10, b),\n];\n"
+        );
+        let updated = register("&[\n];\n", "Day::new(", 1, "    Day::new(1, a),").unwrap();
+        assert_eq!(updated, "&[\n    Day::new(1, a),\n];\n");
+        assert_eq!(
+            register("&[];\n", "Day::new(", 1, "    Day::new(1, a),"),
+            None
+        );
+    }
+}
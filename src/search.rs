@@ -0,0 +1,244 @@
+//! Shortest paths through graphs, given as a function from each node to its successors.
+//!
+//! Graphs are explored lazily, so they needn't be finite as long as a search has a goal that it
+//! can reach.
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The shortest distances from a start node to every node that a search reached, with the means
+/// to recover the paths.
+#[derive(Clone, Debug)]
+pub struct Paths<N, C> {
+    distances: HashMap<N, C>,
+    previous: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Paths<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        let mut distances = HashMap::new();
+        distances.insert(start, zero);
+        Self {
+            distances,
+            previous: HashMap::new(),
+        }
+    }
+
+    /// The distance to a node, if the search reached it.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// The distance to every node that the search reached, including the start.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// Consumes the search, keeping just the distances.
+    pub fn into_distances(self) -> HashMap<N, C> {
+        self.distances
+    }
+
+    /// A shortest path to a node, from the start to the node inclusive, if the search reached it.
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.previous.get(path.last().expect("Path is never empty")) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// A shortest path found by a search.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// The total cost of the path.
+    pub cost: C,
+    /// The nodes along the path, from the start to the goal inclusive.
+    pub nodes: Vec<N>,
+}
+
+/// Finds the shortest distance to every node that can be reached, when every step costs one.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut paths = Paths::new(start.clone(), 0);
+    let mut queue = VecDeque::new();
+    queue.push_back((start, 0));
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !paths.distances.contains_key(&next) {
+                paths.distances.insert(next.clone(), distance + 1);
+                paths.previous.insert(next.clone(), node.clone());
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    paths
+}
+
+// A node waiting to be explored, which the queue orders by priority, lowest first.
+struct Candidate<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+// Explores nodes in order of their cost plus the heuristic's estimate of the cost still to come,
+// until reaching a goal.  Returns the goal, if any, and what was learned on the way.
+fn search<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (Option<N>, Paths<N, C>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut paths = Paths::new(start.clone(), zero);
+    let mut queue = BinaryHeap::new();
+    queue.push(Candidate {
+        priority: heuristic(&start),
+        cost: zero,
+        node: start,
+    });
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        if paths.distance(&node).is_some_and(|best| cost > best) {
+            continue;
+        }
+        if is_goal(&node) {
+            return (Some(node), paths);
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if paths.distance(&next).is_none_or(|best| next_cost < best) {
+                paths.distances.insert(next.clone(), next_cost);
+                paths.previous.insert(next.clone(), node.clone());
+                queue.push(Candidate {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    (None, paths)
+}
+
+/// Finds the shortest distance to every node that can be reached, when each step has a cost.
+pub fn dijkstra<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, |_| C::default(), |_| false).1
+}
+
+/// Finds a shortest path to the nearest goal, when each step has a cost.  The heuristic estimates
+/// the cost from a node to a goal: it speeds up the search, but must never overestimate.  A
+/// heuristic that is always zero makes this Dijkstra's algorithm.
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (goal, paths) = search(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some(Path {
+        cost: paths.distance(&goal).expect("Goal was reached"),
+        nodes: paths.path(&goal).expect("Goal was reached"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small weighted graph, where the direct route is not the shortest.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 7), ('c', 2)],
+            'c' => vec![('b', 3), ('d', 8)],
+            'b' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn breadth_first() {
+        // Counting up by one or by doubling, capped at 20.
+        let paths = bfs(1, |&n: &u32| {
+            vec![n + 1, 2 * n].into_iter().filter(|&n| n <= 20)
+        });
+        assert_eq!(paths.distance(&1), Some(0));
+        assert_eq!(paths.distance(&20), Some(5));
+        assert_eq!(paths.path(&5), Some(vec![1, 2, 4, 5]));
+        assert_eq!(paths.distances().len(), 20);
+        assert_eq!(paths.path(&21), None);
+    }
+
+    #[test]
+    fn distances() {
+        let paths = dijkstra('a', edges);
+        assert_eq!(paths.distance(&'b'), Some(5));
+        assert_eq!(paths.distance(&'d'), Some(6));
+        assert_eq!(paths.path(&'d'), Some(vec!['a', 'c', 'b', 'd']));
+        assert_eq!(paths.distance(&'e'), None);
+    }
+
+    #[test]
+    fn with_heuristic() {
+        let path = astar('a', edges, |_| 0, |&node| node == 'd').unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes, vec!['a', 'c', 'b', 'd']);
+        assert_eq!(astar('b', edges, |_| 0, |&node| node == 'a'), None);
+
+        // Walking along a line towards 10, guided by the distance still to go.
+        let mut explored = 0;
+        let successors = |&n: &i32| {
+            explored += 1;
+            vec![(n - 1, 1), (n + 1, 1)]
+        };
+        let heuristic = |&n: &i32| (10 - n).abs();
+        let path = astar(0, successors, heuristic, |&n| n == 10).unwrap();
+        assert_eq!(path.cost, 10);
+        assert_eq!(explored, 10);
+    }
+}
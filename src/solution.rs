@@ -0,0 +1,157 @@
+//! The interface that each day's solution implements, and the registry of solutions.
+use crate::error::Error;
+use serde::Serialize;
+use std::convert::TryFrom;
+use std::fmt;
+
+/// An answer to one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    /// Most answers are numbers.
+    Number(i64),
+    /// An answer given in words.
+    Text(String),
+    /// Some puzzles draw their answer as a picture, which we keep as a list of rows.
+    Image(Vec<String>),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Number(n) => write!(fmt, "{}", n),
+            Self::Text(text) => write!(fmt, "{}", text),
+            Self::Image(rows) => write!(fmt, "{}", rows.join("\n")),
+        }
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Self::Number(n)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Self::Number(i64::try_from(n).expect("Answer too large"))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Self::Number(i64::try_from(n).expect("Answer too large"))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Self::Number(i64::from(n))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Self::Text(text)
+    }
+}
+
+/// A day's puzzle.
+pub trait Solution {
+    /// Reads the puzzle input, doing whatever work is shared by both parts.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input is not valid for this puzzle.
+    fn parse(input: &str) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Solves the first part of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input has no answer to this part.
+    fn part_one(&self) -> Result<Answer, Error>;
+
+    /// Solves the second part of the puzzle.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the input has no answer to this part.
+    fn part_two(&self) -> Result<Answer, Error>;
+}
+
+/// Reads a puzzle input into a solution.
+pub type ParseFn = fn(&str) -> Result<Box<dyn Solution>, Error>;
+
+/// An interactive session with a puzzle, given its input.
+pub type PlayFn = fn(&str) -> Result<(), Error>;
+
+/// An entry in the registry of solutions.
+pub struct Day {
+    /// The day of the puzzle.
+    pub day: u8,
+
+    /// Reads the puzzle input.
+    pub parse: ParseFn,
+
+    /// An interactive session with the puzzle, for those days that have one.
+    pub play: Option<PlayFn>,
+}
+
+impl Day {
+    /// A day with a solution.
+    pub const fn new(day: u8, parse: ParseFn) -> Self {
+        Self {
+            day,
+            parse,
+            play: None,
+        }
+    }
+
+    /// Adds an interactive session.
+    pub const fn with_play(self, play: PlayFn) -> Self {
+        Self {
+            play: Some(play),
+            ..self
+        }
+    }
+}
+
+/// Parses the input for a solution, for the registry.
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>, Error> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// The solutions for one year's puzzles.
+pub struct Year {
+    /// The year of the puzzles.
+    pub year: u16,
+
+    /// Every day that we have a solution for, in order.
+    pub days: &'static [Day],
+}
+
+/// Every year that we have solutions for, in order.
+pub static YEARS: &[Year] = &[
+    // Each year registers its days in its own module.
+    Year::new(2019, crate::year2019::DAYS),
+];
+
+/// Looks up a year in the registry.
+pub fn find_year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|entry| entry.year == year)
+}
+
+impl Year {
+    /// A year with solutions for the given days.
+    pub const fn new(year: u16, days: &'static [Day]) -> Self {
+        Self { year, days }
+    }
+
+    /// Looks up a day in this year.
+    pub fn find(&self, day: u8) -> Option<&'static Day> {
+        self.days.iter().find(|entry| entry.day == day)
+    }
+}
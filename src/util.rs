@@ -1,4 +1,7 @@
-pub(crate) fn gcd(x: usize, y: usize) -> usize {
+//! Odds and ends of arithmetic.
+
+/// The greatest common divisor of two numbers.
+pub fn gcd(x: usize, y: usize) -> usize {
     let (mut a, mut b) = if x > y { (x, y) } else { (y, x) };
     while b != 0 {
         let remainder = a % b;
@@ -8,6 +11,74 @@ pub(crate) fn gcd(x: usize, y: usize) -> usize {
     a
 }
 
-pub(crate) fn lcm(x: usize, y: usize) -> usize {
+/// The least common multiple of two numbers.
+pub fn lcm(x: usize, y: usize) -> usize {
     (x * y) / gcd(x, y)
 }
+
+/// Calculates `x * y % modulus`, without overflowing so long as `2 * modulus` fits in a `u64`.
+pub fn modular_multiplication(modulus: u64, x: u64, y: u64) -> u64 {
+    let mut result = 0;
+    let mut a = x;
+    let mut b = y;
+    while b != 0 {
+        if (b % 2) == 1 {
+            result = (result + a) % modulus;
+        };
+        a = (a * 2) % modulus;
+        b /= 2;
+    }
+    result
+}
+
+/// Finds the inverse of `n` modulo `modulus`: that is, the number `x` such that
+/// `n * x % modulus == 1`.
+///
+/// # Panics
+///
+/// Panics if `n` and `modulus` are not coprime, so that there is no inverse.
+pub fn modular_inverse(modulus: u64, n: u64) -> u64 {
+    let mut a = modulus;
+    let mut b = n;
+    let mut x0 = 0;
+    let mut x1 = 1;
+
+    // Extended Euclidean algorithm for GCD.
+    while b != 0 {
+        let quotient = a / b;
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+
+        let temp = x1;
+        x1 = (x0 + modular_multiplication(modulus, modulus - quotient, x1)) % modulus;
+        x0 = temp;
+    }
+    assert_eq!(a, 1);
+
+    x0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn divisors_and_multiples() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 5), 1);
+        assert_eq!(lcm(4, 6), 12);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        assert_eq!(modular_multiplication(7, 5, 6), 2);
+        let modulus = 119_315_717_514_047;
+        let large = modulus - 1;
+        assert_eq!(modular_multiplication(modulus, large, large), 1);
+        for n in 1..10 {
+            let inverse = modular_inverse(10007, n);
+            assert_eq!(modular_multiplication(10007, n, inverse), 1);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Solutions to the 2019 puzzles.
+use crate::solution::{parse, Day};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// Every day that we have a solution for, in order.
+pub static DAYS: &[Day] = &[
+    Day::new(1, parse::<day01::Day01>),
+    Day::new(2, parse::<day02::Day02>),
+    Day::new(3, parse::<day03::Day03>),
+    Day::new(4, parse::<day04::Day04>),
+    Day::new(5, parse::<day05::Day05>),
+    Day::new(6, parse::<day06::Day06>),
+    Day::new(7, parse::<day07::Day07>),
+    Day::new(8, parse::<day08::Day08>),
+    Day::new(9, parse::<day09::Day09>),
+    Day::new(10, parse::<day10::Day10>),
+    Day::new(11, parse::<day11::Day11>),
+    Day::new(12, parse::<day12::Day12>),
+    Day::new(13, parse::<day13::Day13>),
+    Day::new(14, parse::<day14::Day14>),
+    Day::new(15, parse::<day15::Day15>),
+    Day::new(16, parse::<day16::Day16>),
+    Day::new(17, parse::<day17::Day17>),
+    Day::new(18, parse::<day18::Day18>),
+    Day::new(19, parse::<day19::Day19>),
+    Day::new(20, parse::<day20::Day20>),
+    Day::new(21, parse::<day21::Day21>).with_play(day21::play),
+    Day::new(22, parse::<day22::Day22>),
+    Day::new(23, parse::<day23::Day23>),
+    Day::new(24, parse::<day24::Day24>),
+    Day::new(25, parse::<day25::Day25>).with_play(day25::play),
+];
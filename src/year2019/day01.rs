@@ -0,0 +1,60 @@
+//! Day 1: The Tyranny of the Rocket Equation.
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
+
+/// The masses of the spacecraft's modules.
+pub struct Day01 {
+    masses: Vec<u64>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let masses = parse_lines(input, "mass")?;
+        Ok(Self { masses })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let fuel: u64 = self.masses.iter().map(|&m| fuel_needed(m)).sum();
+        Ok(fuel.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let fuel: u64 = self.masses.iter().map(|&m| fuel_really_needed(m)).sum();
+        Ok(fuel.into())
+    }
+}
+
+fn fuel_needed(mass: u64) -> u64 {
+    let third = mass / 3;
+    third.saturating_sub(2)
+}
+
+fn fuel_really_needed(mass: u64) -> u64 {
+    let mut total = 0;
+    let mut fuel = fuel_needed(mass);
+    while fuel > 0 {
+        total += fuel;
+        fuel = fuel_needed(fuel);
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuel() {
+        assert_eq!(fuel_needed(12), 2);
+        assert_eq!(fuel_needed(14), 2);
+        assert_eq!(fuel_needed(1969), 654);
+        assert_eq!(fuel_needed(100_756), 33583);
+    }
+
+    #[test]
+    fn fuel_for_fuel() {
+        assert_eq!(fuel_really_needed(14), 2);
+        assert_eq!(fuel_really_needed(1969), 966);
+        assert_eq!(fuel_really_needed(100_756), 50346);
+    }
+}
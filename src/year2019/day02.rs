@@ -0,0 +1,41 @@
+//! Day 2: 1202 Program Alarm.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+
+/// The gravity assist program.
+pub struct Day02 {
+    intcode: IntCode,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut part_one = self.intcode.clone();
+        part_one.write(1, 12);
+        part_one.write(2, 2);
+        part_one.execute()?;
+        Ok(part_one.read(0).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut part_two = self.intcode.clone();
+        for noun in 0..100 {
+            for verb in 0..100 {
+                part_two.reset();
+                part_two.write(1, noun);
+                part_two.write(2, verb);
+                part_two.execute()?;
+                if part_two.read(0) == 19_690_720 {
+                    let answer = 100 * noun + verb;
+                    return Ok(answer.into());
+                }
+            }
+        }
+        Err(Error::new("no noun and verb give 19690720"))
+    }
+}
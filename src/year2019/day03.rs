@@ -0,0 +1,184 @@
+//! Day 3: Crossed Wires.
+use crate::error::Error;
+use crate::grid::{Direction, Point};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Segment {
+    direction: Direction,
+    distance: i64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseDirectionError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseSegmentError;
+
+impl From<ParseDirectionError> for ParseSegmentError {
+    fn from(_err: ParseDirectionError) -> Self {
+        Self
+    }
+}
+impl From<ParseIntError> for ParseSegmentError {
+    fn from(_err: ParseIntError) -> Self {
+        Self
+    }
+}
+
+fn parse_direction(s: &str) -> Result<Direction, ParseDirectionError> {
+    let direction = match s {
+        "U" => Direction::Up,
+        "D" => Direction::Down,
+        "L" => Direction::Left,
+        "R" => Direction::Right,
+        _ => return Err(ParseDirectionError),
+    };
+    Ok(direction)
+}
+
+impl FromStr for Segment {
+    type Err = ParseSegmentError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let split = s.char_indices().nth(1).map_or(s.len(), |(index, _)| index);
+        let direction = parse_direction(&s[..split])?;
+        let distance: i64 = s[split..].parse()?;
+        let step = Segment {
+            direction,
+            distance,
+        };
+        Ok(step)
+    }
+}
+
+/// The paths of the two wires.
+pub struct Day03 {
+    visited1: HashMap<Point, i64>,
+    visited2: HashMap<Point, i64>,
+}
+
+impl Day03 {
+    fn intersections(&self) -> impl Iterator<Item = &Point> {
+        let visited2 = &self.visited2;
+        self.visited1
+            .keys()
+            .filter(move |c| visited2.contains_key(c))
+    }
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut lines = input.lines();
+        let steps1 = wire(lines.next(), 1)?;
+        let steps2 = wire(lines.next(), 2)?;
+
+        let visited1 = visited(&steps1);
+        let visited2 = visited(&steps2);
+        let day = Self { visited1, visited2 };
+        if day.intersections().next().is_none() {
+            return Err(Error::new("the wires never cross"));
+        }
+        Ok(day)
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let closest = self
+            .intersections()
+            .map(|c| c.manhattan(Point::ORIGIN))
+            .min()
+            .expect("The wires cross");
+        Ok(closest.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let shortest = self
+            .intersections()
+            .map(|c| self.visited1[c] + self.visited2[c])
+            .min()
+            .expect("The wires cross");
+        Ok(shortest.into())
+    }
+}
+
+// Reads the path of a wire from a line of the input.
+fn wire(line: Option<&str>, number: usize) -> Result<Vec<Segment>, Error> {
+    let line = line.ok_or_else(|| Error::new(format!("missing wire {}", number)))?;
+    line.trim()
+        .split(',')
+        .map(|word| {
+            word.parse()
+                .map_err(|_| Error::at_line(number, format!("invalid segment: {:?}", word)))
+        })
+        .collect()
+}
+
+// Given a path of `Segment`s, returns a `HashMap` whose keys are the points visited by that path
+// and whose values are the number of steps it took to reach the point.
+fn visited(path: &[Segment]) -> HashMap<Point, i64> {
+    let mut visited = HashMap::new();
+    let mut posn = Point::ORIGIN;
+    let mut steps = 0;
+    for segment in path {
+        for _ii in 1..=segment.distance {
+            posn = posn.step(segment.direction);
+            steps += 1;
+            visited.entry(posn).or_insert(steps);
+        }
+    }
+    visited
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(wires: &str, distance: i64, steps: i64) {
+        let day = Day03::parse(wires).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(distance));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(steps));
+    }
+
+    #[test]
+    fn examples() {
+        check("R8,U5,L5,D3\nU7,R6,D4,L4", 6, 30);
+        check(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72\nU62,R66,U55,R34,D71,R55,D58,R83",
+            159,
+            610,
+        );
+        check(
+            "R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7",
+            135,
+            410,
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let err = Day03::parse(
+            "R8,U5
+U7,X6,D4",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.reason(), "invalid segment: \"X6\"");
+        assert!(Day03::parse("R8,U5").is_err());
+        let err = Day03::parse("R8,U5\nL3,D2").err().unwrap();
+        assert_eq!(err.reason(), "the wires never cross");
+    }
+
+    #[test]
+    fn visits() {
+        let path = "R2,U1"
+            .split(',')
+            .map(|s| s.parse().unwrap())
+            .collect::<Vec<_>>();
+        let visits = visited(&path);
+        assert_eq!(visits.len(), 3);
+        assert_eq!(visits[&Point::new(2, -1)], 3);
+    }
+}
@@ -0,0 +1,128 @@
+//! Day 4: Secure Container.
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+const LENGTH: usize = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Range {
+    low: u32,
+    high: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseRangeError;
+
+impl From<ParseIntError> for ParseRangeError {
+    fn from(_err: ParseIntError) -> Self {
+        Self
+    }
+}
+
+impl FromStr for Range {
+    type Err = ParseRangeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (low, high) = s.trim().split_once('-').ok_or(ParseRangeError)?;
+        let low = low.parse()?;
+        let high = high.parse()?;
+        Ok(Self { low, high })
+    }
+}
+
+// Digits never decrease, so there are only a few thousand candidates: far fewer than there are
+// numbers in the range.  We generate them directly.  The first digit can't be 0, as the password
+// must be a six-digit number.
+fn non_decreasing(digits: &mut Vec<u32>, candidates: &mut Vec<Vec<u32>>) {
+    if digits.len() == LENGTH {
+        candidates.push(digits.clone());
+        return;
+    }
+    let smallest = digits.last().cloned().unwrap_or(1);
+    for digit in smallest..10 {
+        digits.push(digit);
+        non_decreasing(digits, candidates);
+        digits.pop();
+    }
+}
+
+fn value(digits: &[u32]) -> u32 {
+    digits.iter().fold(0, |acc, digit| 10 * acc + digit)
+}
+
+// Since the digits don't decrease, equal digits are always adjacent: so each digit's count is the
+// length of its run.
+fn runs(digits: &[u32]) -> impl Iterator<Item = usize> + '_ {
+    (0..10).map(move |d| digits.iter().filter(|&&digit| digit == d).count())
+}
+
+/// The possible passwords: six digits that never decrease, within the puzzle's range.
+pub struct Day04 {
+    candidates: Vec<Vec<u32>>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let range: Range = input
+            .parse()
+            .map_err(|_| Error::new(format!("invalid range: {:?}", input.trim())))?;
+        let mut candidates = vec![];
+        non_decreasing(&mut vec![], &mut candidates);
+        candidates.retain(|digits| (range.low..=range.high).contains(&value(digits)));
+        Ok(Self { candidates })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let valid = self
+            .candidates
+            .iter()
+            .filter(|digits| runs(digits).any(|run| run >= 2))
+            .count();
+        Ok(valid.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let valid = self
+            .candidates
+            .iter()
+            .filter(|digits| runs(digits).any(|run| run == 2))
+            .count();
+        Ok(valid.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(password: u32) -> (Answer, Answer) {
+        let day = Day04::parse(&format!("{}-{}", password, password)).unwrap();
+        (day.part_one().unwrap(), day.part_two().unwrap())
+    }
+
+    #[test]
+    fn rules() {
+        assert_eq!(count(111_111).0, Answer::Number(1));
+        assert_eq!(count(223_450).0, Answer::Number(0));
+        assert_eq!(count(123_789).0, Answer::Number(0));
+        assert_eq!(count(112_233).1, Answer::Number(1));
+        assert_eq!(count(123_444).1, Answer::Number(0));
+        assert_eq!(count(111_122).1, Answer::Number(1));
+    }
+
+    #[test]
+    fn range() {
+        let day = Day04::parse("100000-111122").unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(10));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(1));
+    }
+
+    #[test]
+    fn six_digits() {
+        let day = Day04::parse("0-111111").unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(1));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(0));
+    }
+}
@@ -0,0 +1,45 @@
+//! Day 5: Sunny with a Chance of Asteroids.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+
+/// The Thermal Environment Supervision Terminal diagnostic program.
+pub struct Day05 {
+    intcode: IntCode,
+}
+
+impl Day05 {
+    // Runs the diagnostic program for a system.  Every test should output zero, and then the final
+    // output is the diagnostic code.
+    fn diagnose(&self, system: i64) -> Result<i64, Error> {
+        let mut program = self.intcode.clone();
+        program.add_input(system);
+        program.execute()?;
+        let mut outputs = vec![];
+        while let Some(value) = program.get_output() {
+            outputs.push(value);
+        }
+        let (code, tests) = outputs
+            .split_last()
+            .ok_or_else(|| Error::new("no diagnostic code"))?;
+        if tests.iter().any(|&t| t != 0) {
+            return Err(Error::new(format!("failed tests: {:?}", tests)));
+        }
+        Ok(*code)
+    }
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(self.diagnose(1)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(self.diagnose(5)?.into())
+    }
+}
@@ -0,0 +1,160 @@
+//! Day 6: Universal Orbit Map.
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Default)]
+struct Node {
+    parent_id: Option<String>,
+    children: Vec<Rc<RefCell<Node>>>,
+}
+
+impl Node {
+    fn set_parent(&mut self, parent_id: &str) {
+        self.parent_id.replace(parent_id.into());
+    }
+
+    fn add_child(&mut self, child: Rc<RefCell<Node>>) {
+        self.children.push(child);
+    }
+}
+
+/// The map of which objects orbit which.
+pub struct Day06 {
+    nodes: HashMap<String, Rc<RefCell<Node>>>,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        // Build our graph.
+        let mut nodes: HashMap<String, Rc<RefCell<Node>>> = HashMap::new();
+        for (index, line) in input.lines().enumerate() {
+            let (centre, orbiter) = line
+                .split_once(')')
+                .ok_or_else(|| Error::at_line(index + 1, format!("invalid orbit: {:?}", line)))?;
+            let centre_node = nodes
+                .entry(centre.into())
+                .or_insert_with(|| Rc::new(RefCell::new(Node::default())))
+                .clone();
+            let orbiting_node = nodes
+                .entry(orbiter.into())
+                .or_insert_with(|| Rc::new(RefCell::new(Node::default())));
+            if let Some(parent_id) = &orbiting_node.borrow().parent_id {
+                let reason = format!("{} already orbits {}", orbiter, parent_id);
+                return Err(Error::at_line(index + 1, reason));
+            }
+            centre_node.borrow_mut().add_child(orbiting_node.clone());
+            orbiting_node.borrow_mut().set_parent(centre);
+        }
+        Ok(Self { nodes })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        // Find the root.
+        let root = self
+            .nodes
+            .get("COM")
+            .ok_or_else(|| Error::new("no centre of mass"))?;
+
+        // Solve via depth-first search.  Each object orbits at most one other, so if we come
+        // across more objects than the map has, the orbits must go round in a circle.
+        let mut total = 0usize;
+        let mut visited = 0;
+        let mut stack = vec![(root.clone(), 0)];
+        while let Some((node, depth)) = stack.pop() {
+            visited += 1;
+            if visited > self.nodes.len() {
+                return Err(Error::new("the orbits of COM form a cycle"));
+            }
+            total += depth;
+            for child in &node.borrow().children {
+                stack.push((child.clone(), depth + 1));
+            }
+        }
+        Ok(total.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        // The shortest path will take us up to our common parent and down again.  So find that
+        // common parent, and do the maths.  The ancestors are listed nearest first, so they agree
+        // from the far end down to the common parent.
+        let you_ancestors = get_ancestors(&self.nodes, "YOU")?;
+        let santa_ancestors = get_ancestors(&self.nodes, "SAN")?;
+        let shared = you_ancestors
+            .iter()
+            .rev()
+            .zip(santa_ancestors.iter().rev())
+            .take_while(|(you, santa)| you == santa)
+            .count();
+        if shared == 0 {
+            return Err(Error::new("YOU and SAN orbit nothing in common"));
+        }
+        let answer = (you_ancestors.len() - shared) + (santa_ancestors.len() - shared);
+        Ok(answer.into())
+    }
+}
+
+// Each object orbits at most one other, so a chain of orbits longer than the map must go round in
+// a circle.
+fn get_ancestors(
+    nodes: &HashMap<String, Rc<RefCell<Node>>>,
+    id: &str,
+) -> Result<Vec<String>, Error> {
+    let mut ancestors = vec![];
+    let mut node = nodes
+        .get(id)
+        .ok_or_else(|| Error::new(format!("{} is not on the map", id)))?;
+    while let Some(parent_id) = &node.borrow().parent_id {
+        if ancestors.len() == nodes.len() {
+            return Err(Error::new(format!("the orbits of {} form a cycle", id)));
+        }
+        ancestors.push(parent_id.clone());
+        node = nodes.get(parent_id).expect("Failed to find parent node!");
+    }
+    Ok(ancestors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn orbits() {
+        let map = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L";
+        assert_eq!(
+            Day06::parse(map).unwrap().part_one().unwrap(),
+            Answer::Number(42)
+        );
+    }
+
+    #[test]
+    fn transfers() {
+        let map = "COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN";
+        assert_eq!(
+            Day06::parse(map).unwrap().part_two().unwrap(),
+            Answer::Number(4)
+        );
+        let day = Day06::parse("COM)A\nA)YOU\nA)SAN").unwrap();
+        assert_eq!(day.part_two().unwrap(), Answer::Number(0));
+    }
+
+    #[test]
+    fn invalid() {
+        let day = Day06::parse("COM)B\nB)YOU").unwrap();
+        assert_eq!(
+            day.part_two().err().unwrap().reason(),
+            "SAN is not on the map"
+        );
+        let day = Day06::parse("A)B\nB)A\nB)SAN\nA)YOU").unwrap();
+        assert!(day.part_one().is_err());
+        assert!(day.part_two().is_err());
+        let err = Day06::parse("COM)B\nB)C\nCOM)C").err().unwrap();
+        assert_eq!(err.line(), Some(3));
+        let day = Day06::parse("COM)B\nB)COM").unwrap();
+        assert!(day.part_one().is_err());
+        let day = Day06::parse("COM)A\nA)YOU\nB)SAN").unwrap();
+        assert!(day.part_two().is_err());
+    }
+}
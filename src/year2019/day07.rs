@@ -0,0 +1,146 @@
+//! Day 7: Amplification Circuit.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+use itertools::Itertools;
+
+struct Amp {
+    intcode: IntCode,
+}
+
+impl Amp {
+    fn new(intcode: IntCode) -> Self {
+        Self { intcode }
+    }
+
+    fn reset(&mut self, phase: i64) {
+        self.intcode.reset();
+        self.intcode.add_input(phase);
+    }
+
+    fn run(&mut self, input: i64) -> Result<Option<i64>, Error> {
+        self.intcode.add_input(input);
+        self.intcode.execute()?;
+        Ok(self.intcode.get_output())
+    }
+}
+
+/// The amplifier controller software.
+pub struct Day07 {
+    intcode: IntCode,
+}
+
+impl Day07 {
+    fn amps(&self) -> Vec<Amp> {
+        (0..5).map(|_| Amp::new(self.intcode.clone())).collect()
+    }
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut amps = self.amps();
+        let mut best_output = 0;
+        for phase_sequence in (0..5).permutations(5) {
+            for (amp, &phase) in amps.iter_mut().zip(&phase_sequence) {
+                amp.reset(phase);
+            }
+            let output = run_line_once(&mut amps, 0)?.ok_or_else(no_output)?;
+            if output > best_output {
+                best_output = output
+            };
+        }
+        Ok(best_output.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut amps = self.amps();
+        let mut best_output = 0;
+        for phase_sequence in (5..10).permutations(5) {
+            for (amp, &phase) in amps.iter_mut().zip(&phase_sequence) {
+                amp.reset(phase);
+            }
+            let output = run_line_repeatedly(&mut amps)?;
+            if output > best_output {
+                best_output = output
+            };
+        }
+        Ok(best_output.into())
+    }
+}
+
+// Passes a signal through the amplifiers, returning `None` if one of them doesn't output.
+fn run_line_once(amps: &mut [Amp], input: i64) -> Result<Option<i64>, Error> {
+    let mut signal = input;
+    for amp in amps {
+        signal = match amp.run(signal)? {
+            Some(output) => output,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(signal))
+}
+
+fn run_line_repeatedly(amps: &mut [Amp]) -> Result<i64, Error> {
+    let mut signal = run_line_once(amps, 0)?.ok_or_else(no_output)?;
+    while let Some(output) = run_line_once(amps, signal)? {
+        signal = output;
+    }
+    Ok(signal)
+}
+
+fn no_output() -> Error {
+    Error::new("an amplifier gave no output")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amplifiers() {
+        for &(program, signal) in &[
+            ("3,15,3,16,1002,16,10,16,1,16,15,15,4,15,99,0,0", 43210),
+            (
+                "3,23,3,24,1002,24,10,24,1002,23,-1,23,101,5,23,23,1,24,23,23,4,23,99,0,0",
+                54321,
+            ),
+            (
+                "3,31,3,32,1002,32,10,32,1001,31,-2,31,1007,31,0,33,1002,33,7,33,1,33,31,31,1,32,\
+                 31,31,4,31,99,0,0,0",
+                65210,
+            ),
+        ] {
+            assert_eq!(
+                Day07::parse(program).unwrap().part_one().unwrap(),
+                Answer::Number(signal)
+            );
+        }
+    }
+
+    #[test]
+    fn feedback_loop() {
+        for &(program, signal) in &[
+            (
+                "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,\
+                 0,0,5",
+                139_629_729,
+            ),
+            (
+                "3,52,1001,52,-5,52,3,53,1,52,56,54,1007,54,5,55,1005,55,26,1001,54,-5,54,1105,1,\
+                 12,1,53,54,53,1008,54,0,55,1001,55,1,55,2,53,55,53,4,53,1001,56,-1,56,1005,56,6,\
+                 99,0,0,0,0,10",
+                18216,
+            ),
+        ] {
+            assert_eq!(
+                Day07::parse(program).unwrap().part_two().unwrap(),
+                Answer::Number(signal)
+            );
+        }
+    }
+}
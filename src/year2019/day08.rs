@@ -0,0 +1,100 @@
+//! Day 8: Space Image Format.
+use crate::error::Error;
+use crate::itertools::Itertools;
+use crate::solution::{Answer, Solution};
+use std::str;
+
+/// The layers of the password image.
+pub struct Day08 {
+    width: usize,
+    layers: Vec<String>,
+}
+
+impl Day08 {
+    fn with_size(input: &str, width: usize, height: usize) -> Result<Self, Error> {
+        let input = input.trim();
+        if let Some(c) = input.chars().find(|c| !c.is_ascii_digit()) {
+            return Err(Error::new(format!("invalid pixel: {:?}", c)));
+        }
+        if input.is_empty() || input.len() % (width * height) != 0 {
+            let reason = format!("image is not made of {}x{} layers", width, height);
+            return Err(Error::new(reason));
+        }
+        let layers = input
+            .as_bytes()
+            .chunks(width * height)
+            .map(|chunk| str::from_utf8(chunk).map(str::to_owned))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Pixels are digits");
+        Ok(Self { width, layers })
+    }
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        Self::with_size(input, 25, 6)
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let layer = self
+            .layers
+            .iter()
+            .min_by_key(|layer| count_char(layer, '0'))
+            .unwrap();
+        let ones = count_char(layer, '1');
+        let twos = count_char(layer, '2');
+        Ok((ones * twos).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut visible = self.layers[0].to_owned();
+        for layer in &self.layers[1..] {
+            visible = add_layers(&visible, layer);
+        }
+        let image = visible
+            .chars()
+            .chunks(self.width)
+            .into_iter()
+            .map(|line| line.collect())
+            .collect();
+        Ok(Answer::Image(image))
+    }
+}
+
+fn count_char(s: &str, c: char) -> usize {
+    s.chars().filter(|&x| x == c).count()
+}
+
+fn add_pixels(upper: char, lower: char) -> char {
+    if upper == '2' {
+        lower
+    } else {
+        upper
+    }
+}
+
+fn add_layers(upper: &str, lower: &str) -> String {
+    upper
+        .chars()
+        .zip(lower.chars())
+        .map(|(u, l)| add_pixels(u, l))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum() {
+        let day = Day08::with_size("123456789012", 3, 2).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(1));
+    }
+
+    #[test]
+    fn decode() {
+        let day = Day08::with_size("0222112222120000", 2, 2).unwrap();
+        let image = vec!["01".to_owned(), "10".to_owned()];
+        assert_eq!(day.part_two().unwrap(), Answer::Image(image));
+    }
+}
@@ -0,0 +1,37 @@
+//! Day 9: Sensor Boost.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+
+/// The BOOST program.
+pub struct Day09 {
+    intcode: IntCode,
+}
+
+impl Day09 {
+    fn boost(&self, mode: i64) -> Result<i64, Error> {
+        let mut program = self.intcode.clone();
+        program.add_input(mode);
+        program.execute()?;
+        program
+            .get_output()
+            .ok_or_else(|| Error::new("the program gave no output"))
+    }
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    // Test mode.
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(self.boost(1)?.into())
+    }
+
+    // Sensor boost mode.
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok(self.boost(2)?.into())
+    }
+}
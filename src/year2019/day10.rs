@@ -0,0 +1,229 @@
+//! Day 10: Monitoring Station.
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use crate::util::gcd;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashSet};
+use std::convert::TryFrom;
+
+#[derive(PartialEq, Eq, Hash)]
+struct Direction {
+    col: isize,
+    row: isize,
+}
+
+impl Direction {
+    fn new(col: isize, row: isize) -> Self {
+        let gcd = gcd(col.unsigned_abs(), row.unsigned_abs());
+        let colstep = col / gcd as isize;
+        let rowstep = row / gcd as isize;
+        Self {
+            col: colstep,
+            row: rowstep,
+        }
+    }
+}
+
+impl Ord for Direction {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Equal things are equal.
+        if self == other {
+            return Ordering::Equal;
+        };
+
+        // We always start at (0, -1).
+        if self.col == 0 && self.row < 0 {
+            return Ordering::Less;
+        };
+
+        if other.col == 0 && other.row < 0 {
+            return Ordering::Greater;
+        };
+
+        // Any direction on the right hand side of the clock is earlier than any direction on the
+        // left hand side of the clock.
+        //
+        // This also takes care of cases with (0, 1).
+        let self_side = self.col.signum();
+        let other_side = other.col.signum();
+        if self_side != other_side {
+            return other_side.cmp(&self_side);
+        };
+
+        // So now we can simply compare the (tangents of) the angles formed.
+        (self.row * other.col).cmp(&(other.row * self.col))
+    }
+}
+
+impl PartialOrd for Direction {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum GridCell {
+    Empty,
+    Asteroid,
+}
+struct ParseGridCellError;
+
+impl TryFrom<char> for GridCell {
+    type Error = ParseGridCellError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        let cell = match c {
+            '.' => GridCell::Empty,
+            '#' => GridCell::Asteroid,
+            _ => return Err(ParseGridCellError),
+        };
+        Ok(cell)
+    }
+}
+
+struct Grid {
+    asteroids: HashSet<(usize, usize)>,
+}
+
+impl Grid {
+    fn get_sightlines(&self, posn: (usize, usize)) -> BTreeMap<Direction, Vec<(usize, usize)>> {
+        let (col, row) = posn;
+        let mut visible = BTreeMap::new();
+        for (acol, arow) in &self.asteroids {
+            if (*acol, *arow) == (col, row) {
+                continue;
+            };
+            let jumpc = *acol as isize - col as isize;
+            let jumpr = *arow as isize - row as isize;
+            let direction = Direction::new(jumpc, jumpr);
+            visible
+                .entry(direction)
+                .or_insert_with(Vec::new)
+                .push((*acol, *arow));
+        }
+
+        visible
+    }
+}
+
+/// The asteroid map, and the best place for a monitoring station.
+pub struct Day10 {
+    grid: Grid,
+    laser: (usize, usize),
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let cells: Vec<Vec<GridCell>> = input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| {
+                line.chars()
+                    .map(|c| {
+                        GridCell::try_from(c).map_err(|_| {
+                            Error::at_line(index + 1, format!("unexpected grid value: {:?}", c))
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        // Build a more convenient representation of the grid.
+        let mut asteroids = HashSet::new();
+        for (row, line) in cells.iter().enumerate() {
+            for (col, cell) in line.iter().enumerate() {
+                if *cell == GridCell::Asteroid {
+                    asteroids.insert((col, row));
+                }
+            }
+        }
+        let grid = Grid { asteroids };
+
+        // Both parts use the asteroid that can see the most others.
+        let laser = *grid
+            .asteroids
+            .iter()
+            .max_by_key(|&posn| grid.get_sightlines(*posn).len())
+            .ok_or_else(|| Error::new("no asteroids"))?;
+        Ok(Self { grid, laser })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(self.grid.get_sightlines(self.laser).len().into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let laser = self.laser;
+        let mut sightlines = self.grid.get_sightlines(laser);
+
+        // Our sightlines are automatically arranged in rotational order, also sort the entries by
+        // their distance from the laser.
+        //
+        // Manhattan distance is fine since they're all in the same direction.
+        for asts in sightlines.values_mut() {
+            asts.sort_by_key(|a| {
+                (laser.0 as isize - a.0 as isize).abs() + (laser.1 as isize - a.1 as isize).abs()
+            });
+            asts.reverse();
+        }
+
+        if self.grid.asteroids.len() <= 200 {
+            return Err(Error::new("fewer than 200 asteroids to vaporize"));
+        }
+        let mut count = 0;
+        loop {
+            for asts in sightlines.values_mut() {
+                if let Some(a) = asts.pop() {
+                    count += 1;
+                    if count == 200 {
+                        let answer = 100 * a.0 + a.1;
+                        return Ok(answer.into());
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small() {
+        let map = ".#..#\n.....\n#####\n....#\n...##";
+        let day = Day10::parse(map).unwrap();
+        assert_eq!(day.laser, (3, 4));
+        assert_eq!(day.part_one().unwrap(), Answer::Number(8));
+        assert!(day.part_two().is_err());
+    }
+
+    #[test]
+    fn large() {
+        let map = "\
+.#..##.###...#######
+##.############..##.
+.#.######.########.#
+.###.#######.####.#.
+#####.##.#.##.###.##
+..#####..#.#########
+####################
+#.####....###.#.#.##
+##.#################
+#####.##.###..####..
+..######..##.#######
+####.##.####...##..#
+.#####..#.######.###
+##...#.##########...
+#.##########.#######
+.####.#.###.###.#.##
+....##.##.###..#####
+.#.#.###########.###
+#.#.#.#####.####.###
+###.##.####.##.#..##";
+        let day = Day10::parse(map).unwrap();
+        assert_eq!(day.laser, (11, 13));
+        assert_eq!(day.part_one().unwrap(), Answer::Number(210));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(802));
+    }
+}
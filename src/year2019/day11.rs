@@ -0,0 +1,113 @@
+//! Day 11: Space Police.
+use crate::error::Error;
+use crate::grid::{self, Direction, Grid, Point};
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Colour {
+    Black,
+    White,
+}
+
+impl From<i64> for Colour {
+    fn from(x: i64) -> Self {
+        match x {
+            0 => Self::Black,
+            _ => Self::White,
+        }
+    }
+}
+
+impl From<Colour> for i64 {
+    fn from(x: Colour) -> Self {
+        match x {
+            Colour::Black => 0,
+            Colour::White => 1,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum Turn {
+    Left,
+    Right,
+}
+
+impl From<i64> for Turn {
+    fn from(x: i64) -> Self {
+        match x {
+            0 => Self::Left,
+            _ => Self::Right,
+        }
+    }
+}
+
+impl Turn {
+    fn apply(self, direction: Direction) -> Direction {
+        match self {
+            Self::Left => direction.turn_left(),
+            Self::Right => direction.turn_right(),
+        }
+    }
+}
+
+/// The hull painting robot's program.
+pub struct Day11 {
+    intcode: IntCode,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut painted = HashMap::new();
+        paint(&mut self.intcode.clone(), &mut painted)?;
+        Ok(painted.len().into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut painted = HashMap::new();
+        painted.insert(Point::ORIGIN, Colour::White);
+        paint(&mut self.intcode.clone(), &mut painted)?;
+
+        let (low, high) = grid::bounds(painted.keys().cloned()).expect("Nothing painted");
+        let size = high - low;
+        let width = (size.x + 1) as usize;
+        let height = (size.y + 1) as usize;
+        let mut hull = Grid::new(width, height, Colour::Black);
+        for (point, colour) in painted {
+            hull[point - low] = colour;
+        }
+        let image = hull.render_rows(|&colour| if colour == Colour::Black { ' ' } else { '#' });
+        Ok(Answer::Image(image))
+    }
+}
+
+fn paint(program: &mut IntCode, painted: &mut HashMap<Point, Colour>) -> Result<(), Error> {
+    let mut pos = Point::ORIGIN;
+    let mut direction = Direction::Up;
+    loop {
+        let paint = painted.get(&pos).unwrap_or(&Colour::Black);
+        let input = i64::from(*paint);
+        program.add_input(input);
+        program.execute()?;
+        match program.get_output() {
+            Some(output1) => {
+                let colour = Colour::from(output1);
+                painted.insert(pos, colour);
+                let output2 = program
+                    .get_output()
+                    .ok_or_else(|| Error::new("the robot painted without turning"))?;
+                let turn = Turn::from(output2);
+                direction = turn.apply(direction);
+                pos = pos.step(direction);
+            }
+            None => return Ok(()),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Day 12: The N-Body Problem.
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
+use crate::util::lcm;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct Vector3D(i64, i64, i64);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseVectorError;
+
+impl From<ParseIntError> for ParseVectorError {
+    fn from(_err: ParseIntError) -> Self {
+        Self
+    }
+}
+
+impl FromStr for Vector3D {
+    type Err = ParseVectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // "<x=-7, y=17, z=-11>"
+        let s = s.trim();
+        let s = s.strip_prefix('<').ok_or(ParseVectorError)?;
+        let s = s.strip_suffix('>').ok_or(ParseVectorError)?;
+        let coordinates = s
+            .split(',')
+            .map(|word| {
+                let value = word.split('=').nth(1).ok_or(ParseVectorError)?;
+                let value = value.parse()?;
+                Ok(value)
+            })
+            .collect::<Result<Vec<i64>, Self::Err>>()?;
+        match coordinates[..] {
+            [x, y, z] => Ok(Vector3D(x, y, z)),
+            _ => Err(ParseVectorError),
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+struct Moon {
+    position: Vector3D,
+    velocity: Vector3D,
+}
+
+impl Moon {
+    fn new(position: Vector3D) -> Self {
+        let velocity = Vector3D(0, 0, 0);
+        Self { position, velocity }
+    }
+
+    fn potential_energy(&self) -> i64 {
+        self.position.0.abs() + self.position.1.abs() + self.position.2.abs()
+    }
+
+    fn kinetic_energy(&self) -> i64 {
+        self.velocity.0.abs() + self.velocity.1.abs() + self.velocity.2.abs()
+    }
+
+    fn energy(&self) -> i64 {
+        self.potential_energy() * self.kinetic_energy()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct System {
+    moons: Vec<Moon>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl System {
+    fn new(moons: Vec<Moon>) -> Self {
+        Self { moons }
+    }
+
+    fn step(&mut self) {
+        // Calculate and apply velocity changes.
+        let positions = self.moons.iter().map(|m| m.position).collect::<Vec<_>>();
+        for moon in &mut self.moons {
+            let delta = Self::get_gravity_delta(&moon.position, &positions);
+            moon.velocity.0 += delta.0;
+            moon.velocity.1 += delta.1;
+            moon.velocity.2 += delta.2;
+        }
+
+        // Update positions.
+        for moon in &mut self.moons {
+            moon.position.0 += moon.velocity.0;
+            moon.position.1 += moon.velocity.1;
+            moon.position.2 += moon.velocity.2;
+        }
+    }
+
+    fn get_gravity_delta(position: &Vector3D, others: &[Vector3D]) -> Vector3D {
+        let mut delta = Vector3D(0, 0, 0);
+        for other in others {
+            delta.0 += (other.0 - position.0).signum();
+            delta.1 += (other.1 - position.1).signum();
+            delta.2 += (other.2 - position.2).signum();
+        }
+        delta
+    }
+
+    fn energy(&self) -> i64 {
+        self.moons.iter().map(|m| m.energy()).sum()
+    }
+
+    fn keep_axis(&mut self, axis: Axis) {
+        match axis {
+            Axis::X => {
+                for moon in &mut self.moons {
+                    moon.position.1 = 0;
+                    moon.position.2 = 0;
+                }
+            }
+            Axis::Y => {
+                for moon in &mut self.moons {
+                    moon.position.0 = 0;
+                    moon.position.2 = 0;
+                }
+            }
+            Axis::Z => {
+                for moon in &mut self.moons {
+                    moon.position.0 = 0;
+                    moon.position.1 = 0;
+                }
+            }
+        }
+    }
+
+    fn find_cycle_length(&self) -> usize {
+        let mut system = self.clone();
+        let mut counter = 0;
+        loop {
+            system.step();
+            counter += 1;
+            if &system == self {
+                break;
+            };
+        }
+        counter
+    }
+}
+
+/// The positions of the moons.
+pub struct Day12 {
+    system: System,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let positions: Vec<Vector3D> = parse_lines(input, "moon")?;
+        let moons = positions.into_iter().map(Moon::new).collect();
+        let system = System::new(moons);
+        Ok(Self { system })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut system = self.system.clone();
+        for _ in 0..1000 {
+            system.step();
+        }
+        Ok(system.energy().into())
+    }
+
+    // Emulate each axis independently, then recombine.
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut x_only = self.system.clone();
+        x_only.keep_axis(Axis::X);
+        let x_cycle = x_only.find_cycle_length();
+
+        let mut y_only = self.system.clone();
+        y_only.keep_axis(Axis::Y);
+        let y_cycle = y_only.find_cycle_length();
+
+        let mut z_only = self.system.clone();
+        z_only.keep_axis(Axis::Z);
+        let z_cycle = z_only.find_cycle_length();
+
+        Ok(lcm(lcm(x_cycle, y_cycle), z_cycle).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIRST: &str = "<x=-1, y=0, z=2>\n<x=2, y=-10, z=-7>\n<x=4, y=-8, z=8>\n<x=3, y=5, z=-1>";
+    const SECOND: &str =
+        "<x=-8, y=-10, z=0>\n<x=5, y=5, z=10>\n<x=2, y=-7, z=3>\n<x=9, y=-8, z=-3>";
+
+    fn energy_after(moons: &str, steps: usize) -> i64 {
+        let mut system = Day12::parse(moons).unwrap().system;
+        for _ in 0..steps {
+            system.step();
+        }
+        system.energy()
+    }
+
+    #[test]
+    fn energy() {
+        assert_eq!(energy_after(FIRST, 10), 179);
+        assert_eq!(energy_after(SECOND, 100), 1940);
+    }
+
+    #[test]
+    fn cycles() {
+        assert_eq!(
+            Day12::parse(FIRST).unwrap().part_two().unwrap(),
+            Answer::Number(2772)
+        );
+        assert_eq!(
+            Day12::parse(SECOND).unwrap().part_two().unwrap(),
+            Answer::Number(4_686_774_924)
+        );
+    }
+}
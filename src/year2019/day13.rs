@@ -0,0 +1,172 @@
+//! Day 13: Care Package.
+use crate::error::Error;
+use crate::intcode::{ExecuteResult, IntCode};
+use crate::solution::{Answer, Solution};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::fmt;
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Wall,
+    Block,
+    HorizontalPaddle,
+    Ball,
+}
+
+impl From<i64> for Tile {
+    fn from(x: i64) -> Self {
+        match x {
+            0 => Self::Empty,
+            1 => Self::Wall,
+            2 => Self::Block,
+            3 => Self::HorizontalPaddle,
+            _ => Self::Ball,
+        }
+    }
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            Self::Empty => " ",
+            Self::Wall => "|",
+            Self::Block => "#",
+            Self::HorizontalPaddle => "_",
+            Self::Ball => "*",
+        };
+        write!(fmt, "{}", s)
+    }
+}
+
+struct Arcade {
+    program: IntCode,
+    screen: Vec<Vec<Tile>>,
+    score: i64,
+    paddle: (usize, usize),
+    ball: (usize, usize),
+}
+
+impl Arcade {
+    fn new(program: IntCode) -> Result<Self, Error> {
+        // Dimensions determined by examining start screen.
+        let screen = vec![vec![Tile::Empty; 42]; 24];
+        let mut arcade = Self {
+            program,
+            screen,
+            score: 0,
+            paddle: (100, 100),
+            ball: (100, 100),
+        };
+
+        // Set free play, and write the starting screen.
+        arcade.program.write(0, 2);
+        arcade.step()?;
+        Ok(arcade)
+    }
+
+    fn game_over(&self) -> bool {
+        !self.screen.iter().any(|row| row.contains(&Tile::Block))
+    }
+
+    fn tilt_joystick(&mut self, tilt: i64) {
+        self.program.add_input(tilt);
+    }
+
+    fn step(&mut self) -> Result<ExecuteResult, Error> {
+        let result = self.program.execute()?;
+        self.update_state()?;
+        Ok(result)
+    }
+
+    fn update_state(&mut self) -> Result<(), Error> {
+        while let Some(x) = self.program.get_output() {
+            let (y, value) = rest_of_tile(&mut self.program)?;
+            if x == -1 && y == 0 {
+                self.score = value;
+            } else {
+                let tile = Tile::from(value);
+                let x = x as usize;
+                let y = y as usize;
+                let pixel = self
+                    .screen
+                    .get_mut(y)
+                    .and_then(|row| row.get_mut(x))
+                    .ok_or_else(|| Error::new(format!("tile at {},{} is off the screen", x, y)))?;
+                *pixel = tile;
+                match tile {
+                    Tile::HorizontalPaddle => self.paddle = (x, y),
+                    Tile::Ball => self.ball = (x, y),
+                    _ => {}
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Reads the y coordinate and the tile id, once the program has output the x coordinate.
+fn rest_of_tile(program: &mut IntCode) -> Result<(i64, i64), Error> {
+    let mut next = || {
+        program
+            .get_output()
+            .ok_or_else(|| Error::new("the program output part of a tile"))
+    };
+    Ok((next()?, next()?))
+}
+
+impl fmt::Display for Arcade {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let output = self
+            .screen
+            .iter()
+            .map(|row| row.iter().map(|t| format!("{}", t)).join(""))
+            .join("\n");
+        writeln!(fmt, "{}", output)?;
+        write!(fmt, "Score: {}", self.score)
+    }
+}
+
+/// The arcade cabinet's game.
+pub struct Day13 {
+    intcode: IntCode,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut program = self.intcode.clone();
+        program.execute()?;
+        let mut counter = 0usize;
+        while let Some(_x) = program.get_output() {
+            let (_y, tile) = rest_of_tile(&mut program)?;
+            if Tile::from(tile) == Tile::Block {
+                counter += 1;
+            }
+        }
+        Ok(counter.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut arcade = Arcade::new(self.intcode.clone())?;
+        let mut result = ExecuteResult::InputNeeded;
+        while !arcade.game_over() {
+            if result == ExecuteResult::Done {
+                return Err(Error::new("the game ended with blocks left"));
+            }
+            let input = match arcade.ball.0.cmp(&arcade.paddle.0) {
+                Ordering::Less => -1,
+                Ordering::Equal => 0,
+                Ordering::Greater => 1,
+            };
+            arcade.tilt_joystick(input);
+            result = arcade.step()?;
+        }
+        Ok(arcade.score.into())
+    }
+}
@@ -0,0 +1,296 @@
+//! Day 14: Space Stoichiometry.
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Ingredient {
+    chemical: String,
+    amount: usize,
+}
+
+impl Ingredient {
+    fn new(chemical: String, amount: usize) -> Self {
+        Self { chemical, amount }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseIngredientError;
+
+impl From<std::num::ParseIntError> for ParseIngredientError {
+    fn from(_err: std::num::ParseIntError) -> Self {
+        Self
+    }
+}
+
+impl FromStr for Ingredient {
+    type Err = ParseIngredientError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // "12 ABC"
+        let mut words = s.split(' ');
+        let amount = words.next().ok_or(ParseIngredientError)?;
+        let amount = amount.parse()?;
+        let chemical = words.next().ok_or(ParseIngredientError)?;
+        let ingredient = Ingredient::new(chemical.to_string(), amount);
+        Ok(ingredient)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Recipe {
+    inputs: Vec<Ingredient>,
+    output: Ingredient,
+}
+
+impl Recipe {
+    fn new(inputs: Vec<Ingredient>, output: Ingredient) -> Self {
+        Self { inputs, output }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseRecipeError;
+
+impl From<ParseIngredientError> for ParseRecipeError {
+    fn from(_err: ParseIngredientError) -> Self {
+        Self
+    }
+}
+
+impl FromStr for Recipe {
+    type Err = ParseRecipeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // "12 ABC, 34 DEF => 56 GHI"
+        let mut segments = s.split("=>");
+
+        let inputs = segments.next().ok_or(ParseRecipeError)?;
+        let inputs = inputs
+            .split(',')
+            .map(|i| i.trim().parse())
+            .collect::<Result<_, _>>()?;
+
+        let output = segments.next().ok_or(ParseRecipeError)?;
+        let output = output.trim().parse()?;
+
+        let recipe = Recipe::new(inputs, output);
+        Ok(recipe)
+    }
+}
+
+/// The nanofactory's reactions.
+pub struct Day14 {
+    recipes: HashMap<String, Recipe>,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let list = parse_lines::<Recipe>(input, "recipe")?;
+        let made = list
+            .iter()
+            .map(|recipe| recipe.output.chemical.as_str())
+            .collect::<HashSet<_>>();
+        for (index, recipe) in list.iter().enumerate() {
+            let output = &recipe.output;
+            if output.chemical == "ORE" || output.amount == 0 {
+                let reason = format!("cannot make {} {}", output.amount, output.chemical);
+                return Err(Error::at_line(index + 1, reason));
+            }
+            let missing = recipe
+                .inputs
+                .iter()
+                .find(|input| input.chemical != "ORE" && !made.contains(input.chemical.as_str()));
+            if let Some(missing) = missing {
+                let reason = format!("no recipe for {}", missing.chemical);
+                return Err(Error::at_line(index + 1, reason));
+            }
+        }
+        if !made.contains("FUEL") {
+            return Err(Error::new("no recipe for FUEL"));
+        }
+
+        let mut recipes = HashMap::new();
+        for recipe in list {
+            recipes.insert(recipe.output.chemical.to_owned(), recipe);
+        }
+        if let Some(chemical) = unmakeable(&recipes) {
+            let reason = format!("the recipes form a cycle, so {} can't be made", chemical);
+            return Err(Error::new(reason));
+        }
+        Ok(Self { recipes })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut needed = HashMap::new();
+        needed.insert("FUEL".to_owned(), 1);
+        let ore_needed = produce(&self.recipes, needed)
+            .ok_or_else(|| Error::new("a fuel needs more ore than can be counted"))?;
+        Ok(ore_needed.into())
+    }
+
+    // Each fuel needs at least one ore, which bounds the search.  Amounts too large to count are
+    // certainly more than we have.
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut lower = 0;
+        let mut upper = 1_000_000_000_000;
+        while lower <= upper {
+            let middle = (lower + upper) / 2;
+            let mut needed = HashMap::new();
+            needed.insert("FUEL".to_owned(), middle);
+            let ore_needed = produce(&self.recipes, needed);
+            if ore_needed.is_none_or(|ore| ore > 1_000_000_000_000) {
+                upper = middle - 1;
+            } else {
+                lower = middle + 1;
+            }
+        }
+        Ok(upper.into())
+    }
+}
+
+// Finds a chemical that can't be made from ore, because making it needs some chemical that needs
+// itself.
+fn unmakeable(recipes: &HashMap<String, Recipe>) -> Option<&str> {
+    let mut made = HashSet::new();
+    made.insert("ORE");
+    loop {
+        let next = recipes
+            .values()
+            .filter(|recipe| !made.contains(recipe.output.chemical.as_str()))
+            .filter(|recipe| {
+                let inputs = &recipe.inputs;
+                inputs
+                    .iter()
+                    .all(|input| made.contains(input.chemical.as_str()))
+            })
+            .map(|recipe| recipe.output.chemical.as_str())
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            break;
+        }
+        made.extend(next);
+    }
+    recipes
+        .keys()
+        .map(String::as_str)
+        .filter(|chemical| !made.contains(chemical))
+        .min()
+}
+
+// The ore needed to make the given chemicals, or `None` if it is too much to count.
+fn produce(recipes: &HashMap<String, Recipe>, mut needed: HashMap<String, usize>) -> Option<u64> {
+    let mut surplus = HashMap::new();
+    let mut ore_used = 0u64;
+    while !needed.is_empty() {
+        let (output, needed_amount) = needed.iter().next().unwrap();
+        let output = output.clone();
+        let needed_amount = *needed_amount;
+        let recipe = &recipes[&output];
+        let multiplier = needed_amount.div_ceil(recipe.output.amount);
+        for ingredient in &recipe.inputs {
+            let mut ingredient_amount = multiplier.checked_mul(ingredient.amount)?;
+            if ingredient.chemical == "ORE" {
+                ore_used = ore_used.checked_add(ingredient_amount as u64)?;
+            } else {
+                surplus
+                    .entry(ingredient.chemical.clone())
+                    .and_modify(|surplus_amount| {
+                        if *surplus_amount > ingredient_amount {
+                            *surplus_amount -= ingredient_amount;
+                            ingredient_amount = 0;
+                        } else {
+                            ingredient_amount -= *surplus_amount;
+                            *surplus_amount = 0;
+                        }
+                    });
+                if ingredient_amount > 0 {
+                    let amount = needed.entry(ingredient.chemical.clone()).or_insert(0);
+                    *amount = amount.checked_add(ingredient_amount)?;
+                }
+            }
+        }
+        needed.remove(&output);
+        let produced_amount = multiplier.checked_mul(recipe.output.amount)?;
+        surplus.insert(output, produced_amount - needed_amount);
+    }
+    Some(ore_used)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        let recipes = "\
+10 ORE => 10 A
+1 ORE => 1 B
+7 A, 1 B => 1 C
+7 A, 1 C => 1 D
+7 A, 1 D => 1 E
+7 A, 1 E => 1 FUEL";
+        assert_eq!(
+            Day14::parse(recipes).unwrap().part_one().unwrap(),
+            Answer::Number(31)
+        );
+
+        let recipes = "\
+9 ORE => 2 A
+8 ORE => 3 B
+7 ORE => 5 C
+3 A, 4 B => 1 AB
+5 B, 7 C => 1 BC
+4 C, 1 A => 1 CA
+2 AB, 3 BC, 4 CA => 1 FUEL";
+        assert_eq!(
+            Day14::parse(recipes).unwrap().part_one().unwrap(),
+            Answer::Number(165)
+        );
+    }
+
+    #[test]
+    fn trillion_ore() {
+        let recipes = "\
+157 ORE => 5 NZVS
+165 ORE => 6 DCFZ
+44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
+12 HKGWZ, 1 GPVTF, 8 PSHF => 9 QDVJ
+179 ORE => 7 PSHF
+177 ORE => 5 HKGWZ
+7 DCFZ, 7 PSHF => 2 XJWVT
+165 ORE => 2 GPVTF
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT";
+        let day = Day14::parse(recipes).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(13312));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(82_892_753));
+    }
+
+    #[test]
+    fn invalid() {
+        let err = Day14::parse("1 ORE => 1 A\n1 A, 2 B => 1 FUEL")
+            .err()
+            .unwrap();
+        assert_eq!(err.line(), Some(2));
+        assert_eq!(err.reason(), "no recipe for B");
+        let err = Day14::parse("1 ORE => 0 A\n1 A => 1 FUEL").err().unwrap();
+        assert_eq!(err.line(), Some(1));
+        let err = Day14::parse("1 ORE => 1 A").err().unwrap();
+        assert_eq!(err.reason(), "no recipe for FUEL");
+        let err = Day14::parse("1 B => 1 A\n1 A => 1 B\n1 A => 1 FUEL")
+            .err()
+            .unwrap();
+        assert_eq!(err.reason(), "the recipes form a cycle, so A can't be made");
+    }
+
+    #[test]
+    fn too_much_ore() {
+        let day = Day14::parse("99999999999999 ORE => 1 FUEL").unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(99_999_999_999_999));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(0));
+        let recipes = "99999999999999 ORE => 1 A\n99999999999999 A => 1 FUEL";
+        assert!(Day14::parse(recipes).unwrap().part_one().is_err());
+    }
+}
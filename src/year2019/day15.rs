@@ -0,0 +1,170 @@
+//! Day 15: Oxygen System.
+use crate::error::Error;
+use crate::grid::{Direction, Point};
+use crate::intcode::IntCode;
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+// The droid's movement commands: north, south, west and east.
+fn command(direction: Direction) -> i64 {
+    match direction {
+        Direction::Up => 1,
+        Direction::Down => 2,
+        Direction::Left => 3,
+        Direction::Right => 4,
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TileContent {
+    Wall,
+    Empty,
+    Oxygen,
+}
+
+struct Droid {
+    program: IntCode,
+}
+
+impl Droid {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn try_step(&mut self, direction: Direction) -> Result<TileContent, Error> {
+        self.program.add_input(command(direction));
+        self.program.execute()?;
+        match self.program.get_output() {
+            Some(0) => Ok(TileContent::Wall),
+            Some(1) => Ok(TileContent::Empty),
+            Some(2) => Ok(TileContent::Oxygen),
+            Some(status) => Err(Error::new(format!("invalid status code: {}", status))),
+            None => Err(Error::new("the droid did not report")),
+        }
+    }
+
+    fn step(&mut self, direction: Direction) -> Result<(), Error> {
+        if self.try_step(direction)? == TileContent::Wall {
+            return Err(Error::new("the droid could not retrace its steps"));
+        }
+        Ok(())
+    }
+
+    fn peek(&mut self, direction: Direction) -> Result<TileContent, Error> {
+        let content = self.try_step(direction)?;
+        if content != TileContent::Wall {
+            self.step(direction.opposite())?;
+        }
+        Ok(content)
+    }
+}
+
+/// The area explored by the repair droid, and the location of the oxygen system.
+pub struct Day15 {
+    reachable: HashSet<Point>,
+    oxygen_position: Point,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode: IntCode = input.parse()?;
+
+        // Use the droid to explore the world, depth first.  We will learn all the reachable
+        // tiles, as well as the oxygen's position.
+        let mut visited = HashSet::new();
+        let mut oxygen_position = None;
+        let origin = Point::ORIGIN;
+        let mut droid = Droid::new(intcode);
+        let mut path: Vec<Direction> = vec![];
+        let mut stack = vec![(origin, 0, None)];
+        while let Some((position, depth, inwards_direction)) = stack.pop() {
+            if !visited.insert(position) {
+                continue;
+            };
+
+            // Back up, and step to the new place (except at the root).
+            if let Some(forward) = inwards_direction {
+                let mut excess = path.split_off(depth - 1);
+                while let Some(step) = excess.pop() {
+                    droid.step(step.opposite())?;
+                }
+                droid.step(forward)?;
+                path.push(forward);
+            }
+
+            // Look for unvisited neighbours.
+            for &direction in &Direction::ALL {
+                let new_position = position.step(direction);
+                let content = droid.peek(direction)?;
+                if content != TileContent::Wall {
+                    stack.push((new_position, depth + 1, Some(direction)));
+                    if content == TileContent::Oxygen {
+                        oxygen_position.replace(new_position);
+                    }
+                }
+            }
+        }
+
+        let oxygen_position = oxygen_position.ok_or_else(|| Error::new("no oxygen system"))?;
+        Ok(Self {
+            reachable: visited,
+            oxygen_position,
+        })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let distances = get_distances_from(&self.reachable, Point::ORIGIN);
+        Ok(distances[&self.oxygen_position].into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let distances = get_distances_from(&self.reachable, self.oxygen_position);
+        Ok((*distances.values().max().unwrap()).into())
+    }
+}
+
+fn get_distances_from(reachable: &HashSet<Point>, start: Point) -> HashMap<Point, usize> {
+    let paths = search::bfs(start, |position| {
+        let neighbours = position.neighbours();
+        neighbours.filter(|neighbour| reachable.contains(neighbour))
+    });
+    paths.into_distances()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Builds the droid's findings from a map, with the droid starting at 'D'.
+    fn explored(map: &str) -> Day15 {
+        let mut reachable = HashSet::new();
+        let mut oxygen_position = None;
+        let mut origin = None;
+        for (y, line) in map.lines().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let position = Point::new(x as i64, y as i64);
+                match c {
+                    '.' => {}
+                    'O' => oxygen_position = Some(position),
+                    'D' => origin = Some(position),
+                    _ => continue,
+                }
+                reachable.insert(position);
+            }
+        }
+        let origin = origin.unwrap();
+        let shift = |position| position - origin;
+        Day15 {
+            reachable: reachable.into_iter().map(shift).collect(),
+            oxygen_position: shift(oxygen_position.unwrap()),
+        }
+    }
+
+    #[test]
+    fn oxygen() {
+        let day = explored(" ##   \n#D.## \n#.#..#\n#.O.# \n ###  ");
+        assert_eq!(day.part_one().unwrap(), Answer::Number(3));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(4));
+    }
+}
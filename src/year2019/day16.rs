@@ -0,0 +1,142 @@
+//! Day 16: Flawed Frequency Transmission.
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
+use std::iter::repeat_n;
+static BASE: [i32; 4] = [0, 1, 0, -1];
+
+/// The signal.
+pub struct Day16 {
+    digits: Vec<u32>,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let digits = input
+            .trim()
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .ok_or_else(|| Error::new(format!("invalid digit: {:?}", c)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if digits.len() < 8 {
+            return Err(Error::new("the signal is shorter than 8 digits"));
+        }
+        Ok(Self { digits })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let output = (0..100).fold(self.digits.clone(), |acc, _| fft(&acc));
+        Ok(to_text(&output[0..8]).into())
+    }
+
+    // The trick is that this at this point in the message the patterns are just: a lot of zeros
+    // followed by a lot of ones.  That only holds in the second half of the signal.
+    fn part_two(&self) -> Result<Answer, Error> {
+        let offset = self.digits[..7]
+            .iter()
+            .fold(0, |acc, &d| 10 * acc + d as usize);
+        let length = self.digits.len() * 10000;
+        if offset < length / 2 || offset + 8 > length {
+            let reason = format!(
+                "message offset {} is not in the second half of the signal",
+                offset
+            );
+            return Err(Error::new(reason));
+        }
+        let part_two = self.digits.repeat(10000)[offset..].to_owned();
+        let output = (0..100).fold(part_two, |acc, _| fast_partial_fft(&acc));
+        Ok(to_text(&output[0..8]).into())
+    }
+}
+
+fn to_text(digits: &[u32]) -> String {
+    digits
+        .iter()
+        .map(|&d| std::char::from_digit(d, 10).unwrap())
+        .collect()
+}
+
+fn get_pattern(multiplier: usize) -> impl Iterator<Item = i32> {
+    BASE.iter()
+        .flat_map(move |&n| repeat_n(n, multiplier))
+        .cycle()
+        .skip(1)
+}
+
+fn fft_at(digits: &[u32], position: usize) -> u32 {
+    let pattern = get_pattern(position + 1);
+    let value = digits
+        .iter()
+        .zip(pattern)
+        .fold(0i32, |acc, (&d, p)| acc + (d as i32) * p)
+        .unsigned_abs();
+    value % 10
+}
+
+fn fft(digits: &[u32]) -> Vec<u32> {
+    let length = digits.len();
+    (0..length).map(|p| fft_at(digits, p)).collect()
+}
+
+fn fast_partial_fft(digits: &[u32]) -> Vec<u32> {
+    let mut output: Vec<u32> = digits
+        .iter()
+        .rev()
+        .scan(0, |state, &d| {
+            *state = (*state + d) % 10;
+            Some(*state)
+        })
+        .collect();
+    output.reverse();
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Vec<u32> {
+        text.chars().map(|c| c.to_digit(10).unwrap()).collect()
+    }
+
+    #[test]
+    fn phases() {
+        let mut signal = digits("12345678");
+        for expected in &["48226158", "34040438", "03415518", "01029498"] {
+            signal = fft(&signal);
+            assert_eq!(to_text(&signal), *expected);
+        }
+    }
+
+    #[test]
+    fn hundred_phases() {
+        for &(signal, expected) in &[
+            ("80871224585914546619083218645595", "24176176"),
+            ("19617804207202209144916044189917", "73745418"),
+            ("69317163492948606335995924319873", "52432133"),
+        ] {
+            let answer = Day16::parse(signal).unwrap().part_one().unwrap();
+            assert_eq!(answer, Answer::Text(expected.to_owned()));
+        }
+    }
+
+    #[test]
+    fn real_signal() {
+        for &(signal, expected) in &[
+            ("03036732577212944063491565474664", "84462026"),
+            ("02935109699940807407585447034323", "78725270"),
+            ("03081770884921959731165446850517", "53553731"),
+        ] {
+            let answer = Day16::parse(signal).unwrap().part_two().unwrap();
+            assert_eq!(answer, Answer::Text(expected.to_owned()));
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Day16::parse("1234567").is_err());
+        let day = Day16::parse("80871224585914546619083218645595").unwrap();
+        assert!(day.part_two().is_err());
+    }
+}
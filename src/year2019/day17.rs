@@ -0,0 +1,188 @@
+//! Day 17: Set and Forget.
+use crate::error::Error;
+use crate::grid::{Direction, Grid};
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+use std::convert::Infallible;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum TileContent {
+    Scaffold,
+    Robot(Direction),
+    Empty,
+}
+
+impl From<char> for TileContent {
+    fn from(x: char) -> Self {
+        match x {
+            '#' => Self::Scaffold,
+            '^' => Self::Robot(Direction::Up),
+            'v' => Self::Robot(Direction::Down),
+            '<' => Self::Robot(Direction::Left),
+            '>' => Self::Robot(Direction::Right),
+            _ => Self::Empty,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Robot {
+    program: IntCode,
+}
+
+impl Robot {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn get_text(&mut self) -> Result<(String, Option<i64>), Error> {
+        let mut view = String::new();
+        let mut extra = None;
+        self.program.execute()?;
+        while let Some(output) = self.program.get_output() {
+            if output > 255 {
+                extra.replace(output);
+            };
+            view.push(output as u8 as char);
+        }
+        Ok((view, extra))
+    }
+
+    fn submit_text(&mut self, text: &str) -> Result<(), Error> {
+        for c in text.chars() {
+            self.program.add_input(c as u8 as i64);
+        }
+        self.program.add_input(b'\n' as i64);
+        self.program.execute()?;
+        Ok(())
+    }
+}
+
+/// The vacuum robot's program.
+pub struct Day17 {
+    intcode: IntCode,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let (view, _) = Robot::new(self.intcode.clone()).get_text()?;
+        Ok(alignment(&view)?.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        // Our initial view looks like this.
+        //
+        // ..............#####............................
+        // ..............#...#............................
+        // ..............#...#............................
+        // ..............#...#............................
+        // ..............#...#............................
+        // ..............#...#............................
+        // ..............#...#.....#############..........
+        // ..............#...#.....#...........#..........
+        // ..............#.#####...#.#############........
+        // ..............#.#.#.#...#.#.........#.#........
+        // ..............#.#.#############.....#.#........
+        // ..............#.#...#...#.#...#.....#.#........
+        // ..............#######...#.#.###########........
+        // ................#.......#.#.#.#.....#..........
+        // ........#########.......#.#####.....#####......
+        // ........#...............#...#...........#......
+        // ........#...............#...#...........#......
+        // ........#...............#...#...........#......
+        // #########.....###########...#...........#......
+        // #.............#.............#...........#......
+        // #.............#.........#######.........#......
+        // #.............#.........#...#.#.........#......
+        // #.............#############.#.#.........######^
+        // #.......................#.#.#.#................
+        // #.......................#####.#................
+        // #.........................#...#................
+        // #.........................#...#................
+        // #.........................#...#................
+        // #.........................#...#................
+        // #.........................#...#................
+        // #######...................#...#................
+        // ......#...................#...#................
+        // ......#...................#####................
+        // ......#........................................
+        // ......#........................................
+        // ......#........................................
+        // ......#........................................
+        // ......#........................................
+        // ......#####....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#....................................
+        // ..........#############........................
+        //
+        // By inspection, the path we want is:
+        //
+        // L6,R8,L4,R8,L12,L12,R10,L4,L12,R10,L4,L12,L6,L4,
+        // L4,L12,R10,L4,L12,L6,L4,L4,L12,R10,L4,L12,L6,L4,
+        // L4,L6,R8,L4,R8,L12,L6,R8,L4,R8,L12
+        //
+        // Which we can compress as:
+        //
+        // A,B,B,C,B,C,B,C,A,A
+        //
+        // A = L,6,R,8,L,4,R,8,L,12
+        // B = L,12,R,10,L,4
+        // C = L,12,L,6,L,4,L,4
+        let mut robot = Robot::new(self.intcode.clone());
+        robot.program.write(0, 2);
+        let (_view, _) = robot.get_text()?;
+        let (_main, _) = robot.get_text()?;
+        robot.submit_text("A,B,B,C,B,C,B,C,A,A")?;
+        let (_function_a, _) = robot.get_text()?;
+        robot.submit_text("L,6,R,8,L,4,R,8,L,12")?;
+        let (_function_b, _) = robot.get_text()?;
+        robot.submit_text("L,12,R,10,L,4")?;
+        let (_function_c, _) = robot.get_text()?;
+        robot.submit_text("L,12,L,6,L,4,L,4")?;
+        let (_video, _) = robot.get_text()?;
+        robot.submit_text("n")?;
+        let (_view, dust) = robot.get_text()?;
+        dust.map(Answer::from)
+            .ok_or_else(|| Error::new("the robot did not report the dust it collected"))
+    }
+}
+
+// The sum of the alignment parameters of the scaffold intersections.
+fn alignment(view: &str) -> Result<i64, Error> {
+    let map = Grid::parse(view, |c| Ok::<_, Infallible>(TileContent::from(c)))?;
+    let is_scaffold = |point| map.get(point) == Some(&TileContent::Scaffold);
+    let alignment = map
+        .points()
+        .filter(|&point| is_scaffold(point) && point.neighbours().all(is_scaffold))
+        .map(|point| point.x * point.y)
+        .sum();
+    Ok(alignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alignment_parameters() {
+        let view = "\
+..#..........
+..#..........
+#######...###
+#.#...#...#.#
+#############
+..#...#...#..
+..#####...^..";
+        assert_eq!(alignment(view), Ok(76));
+    }
+}
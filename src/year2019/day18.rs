@@ -0,0 +1,771 @@
+//! Day 18: Many-Worlds Interpretation.
+use crate::error::Error;
+use crate::grid::{Direction, Grid, Point};
+use crate::search::{self, Path};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::convert::TryFrom;
+
+// Keys are lower case letters, and each door is the upper case of the letter of its key.
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
+enum TileContent {
+    Wall,
+    Empty,
+    Me,
+    Key(char),
+    Door(char),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseTileError;
+
+impl TryFrom<char> for TileContent {
+    type Error = ParseTileError;
+
+    fn try_from(x: char) -> Result<Self, Self::Error> {
+        let content = match x {
+            '#' => Self::Wall,
+            '.' => Self::Empty,
+            '@' => Self::Me,
+            _ if x.is_lowercase() => Self::Key(x),
+            _ if x.is_uppercase() => Self::Door(x),
+            _ => return Err(ParseTileError),
+        };
+        Ok(content)
+    }
+}
+
+impl TileContent {
+    fn is_visitable(self) -> bool {
+        !matches!(self, Self::Wall)
+    }
+
+    fn to_char(self) -> char {
+        match self {
+            Self::Wall => '#',
+            Self::Empty => '.',
+            Self::Me => '@',
+            Self::Key(c) | Self::Door(c) => c,
+        }
+    }
+}
+
+// A set of keys, by their index in the vault's list of keys.
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq)]
+struct KeySet(u128);
+
+impl KeySet {
+    // The most keys that a vault can have.
+    const CAPACITY: usize = 128;
+
+    fn with(self, key: usize) -> Self {
+        Self(self.0 | 1 << key)
+    }
+
+    fn contains(self, key: usize) -> bool {
+        self.0 & 1 << key != 0
+    }
+
+    fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+}
+
+// Where each robot is, either as a point or as a place in the key graph, and the keys collected.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct SearchNode<P> {
+    positions: Vec<P>,
+    collected: KeySet,
+}
+
+impl<P> SearchNode<P> {
+    fn new(positions: Vec<P>) -> Self {
+        Self {
+            positions,
+            collected: KeySet::default(),
+        }
+    }
+}
+
+/// How the keys were collected: the order, and every step that each robot took.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    /// The total number of steps.
+    pub steps: usize,
+    /// The keys in the order they were collected, each with the robot that collected it.
+    pub keys: Vec<(usize, char)>,
+    /// The tiles that each robot walked, from its start to its last key inclusive.
+    pub walks: Vec<Vec<Point>>,
+    map: Grid<TileContent>,
+}
+
+impl Route {
+    // Follows the search from one set of robot positions to the next, filling in the tiles that
+    // the robot that moved walked along.
+    fn new(map: Grid<TileContent>, positions: &[Vec<Point>], steps: usize) -> Self {
+        let mut walks = positions[0]
+            .iter()
+            .map(|&start| vec![start])
+            .collect::<Vec<_>>();
+        let mut keys = Vec::new();
+        let mut collected = HashSet::new();
+        for pair in positions.windows(2) {
+            let moved = (0..walks.len()).find(|&robot| pair[0][robot] != pair[1][robot]);
+            let robot = moved.expect("Every step of the search moves a robot");
+            let (from, to) = (pair[0][robot], pair[1][robot]);
+            // The search took the shortest way through the doors that were open, so this finds
+            // one just as short.
+            let paths = search::bfs(from, |&position| {
+                let open = |neighbour: &Point| match map.get(*neighbour) {
+                    None | Some(TileContent::Wall) => false,
+                    Some(TileContent::Door(d)) => d.to_lowercase().all(|k| collected.contains(&k)),
+                    Some(_) => true,
+                };
+                position.neighbours().filter(open).collect::<Vec<_>>()
+            });
+            let tiles = paths.path(&to).expect("Key was reached");
+            walks[robot].extend(tiles.into_iter().skip(1));
+            if let TileContent::Key(key) = map[to] {
+                keys.push((robot, key));
+                collected.insert(key);
+            }
+        }
+        Self {
+            steps,
+            keys,
+            walks,
+            map,
+        }
+    }
+
+    /// The directions of each robot's steps.
+    pub fn directions(&self) -> Vec<Vec<Direction>> {
+        let direction = |from: Point, to: Point| {
+            let offset = to - from;
+            let mut directions = Direction::ALL.iter().copied();
+            directions
+                .find(|direction| direction.offset() == offset)
+                .expect("Robots move a step at a time")
+        };
+        let walks = self.walks.iter();
+        walks
+            .map(|walk| walk.windows(2).map(|w| direction(w[0], w[1])).collect())
+            .collect()
+    }
+
+    /// Draws the map, marking the robots' starts with `@` and the open tiles they walked along
+    /// with `*`.
+    pub fn render(&self) -> String {
+        let rows = self.map.render_rows(|&content| content.to_char());
+        let mut rows = rows
+            .into_iter()
+            .map(|row| row.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for walk in &self.walks {
+            for (index, &position) in walk.iter().enumerate() {
+                let tile = &mut rows[position.y as usize][position.x as usize];
+                if index == 0 {
+                    *tile = '@';
+                } else if self.map[position] == TileContent::Empty {
+                    *tile = '*';
+                }
+            }
+        }
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().collect::<String>());
+        rows.collect::<Vec<_>>().join("\n")
+    }
+}
+
+/// The map of the vault.
+pub struct Day18 {
+    map: Grid<TileContent>,
+    starts: Vec<Point>,
+    keys: Vec<(char, Point)>,
+    key_index: HashMap<char, usize>,
+    all_keys: KeySet,
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let map = Grid::parse(input, TileContent::try_from)?;
+        let starts = map
+            .iter()
+            .filter(|(_, &content)| content == TileContent::Me)
+            .map(|(position, _)| position)
+            .collect::<Vec<_>>();
+        if starts.is_empty() {
+            return Err(Error::new("no entrance"));
+        }
+        let mut keys = map
+            .iter()
+            .filter_map(|(position, &content)| match content {
+                TileContent::Key(c) => Some((c, position)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+        if keys.len() > KeySet::CAPACITY {
+            let reason = format!("more than {} keys", KeySet::CAPACITY);
+            return Err(Error::new(reason));
+        }
+        let key_index = keys
+            .iter()
+            .enumerate()
+            .map(|(index, &(key, _))| (key, index))
+            .collect::<HashMap<_, _>>();
+        if key_index.len() < keys.len() {
+            return Err(Error::new("more than one key for the same door"));
+        }
+        for (_, &content) in map.iter() {
+            if let TileContent::Door(c) = content {
+                if door_key(c, &key_index).is_none() {
+                    return Err(Error::new(format!("no key for door {:?}", c)));
+                }
+            }
+        }
+        let all_keys = (0..keys.len()).fold(KeySet::default(), KeySet::with);
+        Ok(Self {
+            map,
+            starts,
+            keys,
+            key_index,
+            all_keys,
+        })
+    }
+
+    // Find shortest path to a state where we've collected all keys.
+    fn part_one(&self) -> Result<Answer, Error> {
+        Ok(self.search(&self.map, &self.starts)?.cost.into())
+    }
+
+    // Part two is much the same, but with the entrance split between four robots.
+    fn part_two(&self) -> Result<Answer, Error> {
+        let (map, starts) = self.split_entrance();
+        Ok(self.search(&map, &starts)?.cost.into())
+    }
+}
+
+impl Day18 {
+    /// The shortest route that collects every key.  Without robots, this starts from every
+    /// entrance on the map, as in the first part of the puzzle.  With robots, a single entrance
+    /// is first split between four, as in the second part.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the robots can't collect every key.
+    pub fn route(&self, robots: bool) -> Result<Route, Error> {
+        let (map, starts) = if robots {
+            self.split_entrance()
+        } else {
+            (self.map.clone(), self.starts.clone())
+        };
+        let path = self.search(&map, &starts)?;
+        Ok(Route::new(map, &path.nodes, path.cost))
+    }
+
+    /// The fewest steps to collect every key, as `route` finds them, but without the key graph
+    /// or a heuristic: the search finds the ways to the keys from each place only when it gets
+    /// there.  Much slower, but simpler, so it's kept to check and benchmark the faster search
+    /// against.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the robots can't collect every key.
+    pub fn steps_without_graph(&self, robots: bool) -> Result<usize, Error> {
+        let (map, starts) = if robots {
+            self.split_entrance()
+        } else {
+            (self.map.clone(), self.starts.clone())
+        };
+        let mut distance_table = HashMap::new();
+        let successors = |node: &SearchNode<Point>| {
+            let mut next = Vec::new();
+            for (bot, position) in node.positions.iter().enumerate() {
+                let distances = distance_table
+                    .entry(*position)
+                    .or_insert_with(|| self.distances_to_keys(&map, *position));
+                for edge in distances.iter() {
+                    if node.collected.contains(edge.key) || !edge.doors.is_subset(node.collected) {
+                        continue;
+                    }
+                    let mut new_node = node.clone();
+                    new_node.collected = node.collected.with(edge.key);
+                    new_node.positions[bot] = self.keys[edge.key].1;
+                    next.push((new_node, edge.distance));
+                }
+            }
+            next
+        };
+        let path = search::astar(
+            SearchNode::new(starts),
+            successors,
+            |_| 0,
+            |node| node.collected == self.all_keys,
+        );
+        Ok(path.ok_or_else(unreachable_keys)?.cost)
+    }
+
+    // Walls off a single entrance and its neighbours, leaving a robot at each diagonal that
+    // isn't a wall.  A map with several entrances is already split.
+    fn split_entrance(&self) -> (Grid<TileContent>, Vec<Point>) {
+        let mut map = self.map.clone();
+        if self.starts.len() != 1 {
+            return (map, self.starts.clone());
+        }
+        let me = self.starts[0];
+        for position in std::iter::once(me).chain(me.neighbours()) {
+            if let Some(content) = map.get_mut(position) {
+                *content = TileContent::Wall;
+            }
+        }
+        let starts = iproduct!(
+            &[Direction::Up, Direction::Down],
+            &[Direction::Left, Direction::Right]
+        )
+        .map(|(&vertical, &horizontal)| me.step(vertical).step(horizontal))
+        .filter(|&start| map.get(start).is_some_and(|p| p.is_visitable()))
+        .collect();
+        (map, starts)
+    }
+
+    // Searches for the fewest steps for robots at the starts to collect every key, each moving
+    // from key to key in turn.  Returns where the robots are after each key.
+    fn search(
+        &self,
+        map: &Grid<TileContent>,
+        starts: &[Point],
+    ) -> Result<Path<Vec<Point>, usize>, Error> {
+        let graph = KeyGraph::new(self, map, starts);
+        let keys = self.keys.len();
+        let path = search::astar(
+            SearchNode::new((keys..keys + starts.len()).collect()),
+            |node| graph.successors(node),
+            |node| graph.estimate(node),
+            |node| node.collected == self.all_keys,
+        );
+        let path = path.ok_or_else(unreachable_keys)?;
+        let nodes = path.nodes.iter().map(|node| {
+            let places = node.positions.iter();
+            places.map(|&place| graph.points[place]).collect()
+        });
+        Ok(Path {
+            cost: path.cost,
+            nodes: nodes.collect(),
+        })
+    }
+
+    // Lists the ways to the keys that can be reached from a point.  Where the vault has loops, a
+    // longer way to a key can need fewer doors, so a key can have several: each is kept unless
+    // one at least as short needs only some of its doors.
+    fn distances_to_keys(&self, map: &Grid<TileContent>, start: Point) -> Vec<Edge> {
+        let mut edges = Vec::new();
+        // The sets of doors on the ways found to each tile, shortest first.
+        let mut ways: HashMap<Point, Vec<KeySet>> = HashMap::new();
+        ways.insert(start, vec![KeySet::default()]);
+        let mut queue = VecDeque::new();
+        queue.push_back((start, KeySet::default(), 0));
+        while let Some((position, doors, distance)) = queue.pop_front() {
+            if let TileContent::Key(c) = map[position] {
+                if position != start {
+                    let key = self.key_index[&c];
+                    edges.push(Edge {
+                        key,
+                        distance,
+                        doors,
+                    });
+                }
+            }
+            for neighbour in position.neighbours() {
+                let doors = match map.get(neighbour) {
+                    None | Some(TileContent::Wall) => continue,
+                    Some(&TileContent::Door(d)) => {
+                        doors.with(door_key(d, &self.key_index).expect("Doors have keys"))
+                    }
+                    Some(_) => doors,
+                };
+                let found = ways.entry(neighbour).or_default();
+                if found.iter().any(|&shorter| shorter.is_subset(doors)) {
+                    continue;
+                }
+                found.push(doors);
+                queue.push_back((neighbour, doors, distance + 1));
+            }
+        }
+        edges
+    }
+}
+
+// A way from somewhere to a key: how far it is, and the doors on the way.
+#[derive(Copy, Clone, Debug)]
+struct Edge {
+    key: usize,
+    distance: usize,
+    doors: KeySet,
+}
+
+// The keys and the robots' starts, with the ways between them, found before searching so that
+// the search can move straight from key to key.  Places are numbered with the keys first, by
+// their index, then the starts.
+struct KeyGraph {
+    points: Vec<Point>,
+    edges: Vec<Vec<Edge>>,
+    // The distance from each place to each key, ignoring doors, or `None` if it can't be reached.
+    distances: Vec<Vec<Option<usize>>>,
+}
+
+impl KeyGraph {
+    fn new(vault: &Day18, map: &Grid<TileContent>, starts: &[Point]) -> Self {
+        let keys = vault.keys.iter().map(|&(_, position)| position);
+        let points = keys.chain(starts.iter().copied()).collect::<Vec<_>>();
+        let edges = points
+            .iter()
+            .map(|&point| vault.distances_to_keys(map, point))
+            .collect::<Vec<_>>();
+        let distances = edges
+            .iter()
+            .map(|edges| {
+                let mut distances = vec![None; vault.keys.len()];
+                // The ways to each key are listed shortest first.
+                for edge in edges {
+                    distances[edge.key].get_or_insert(edge.distance);
+                }
+                distances
+            })
+            .collect();
+        Self {
+            points,
+            edges,
+            distances,
+        }
+    }
+
+    // A robot can go for a key once it has the keys to the doors on the way.  It may pass other
+    // keys without counting them, but that does no harm: the search also tries collecting them
+    // first, which costs no more.
+    fn successors(&self, node: &SearchNode<usize>) -> Vec<(SearchNode<usize>, usize)> {
+        let mut next = Vec::new();
+        for (bot, &place) in node.positions.iter().enumerate() {
+            for edge in &self.edges[place] {
+                let open = edge.doors.is_subset(node.collected);
+                if node.collected.contains(edge.key) || !open {
+                    continue;
+                }
+                let mut new_node = node.clone();
+                new_node.collected = node.collected.with(edge.key);
+                new_node.positions[bot] = edge.key;
+                next.push((new_node, edge.distance));
+            }
+        }
+        next
+    }
+
+    // Estimates the steps still to take as the weight of a minimum spanning tree that joins the
+    // keys left to collect to the robots.  The robots' routes from here join them all up too,
+    // so they can be no shorter.
+    fn estimate(&self, node: &SearchNode<usize>) -> usize {
+        let mut left = (0..self.distances[0].len())
+            .filter(|&key| !node.collected.contains(key))
+            .collect::<Vec<_>>();
+        // Prim's algorithm, starting from the robots, which are joined already.
+        let robots = node.positions.iter();
+        let mut best = left
+            .iter()
+            .map(|&key| robots.clone().filter_map(|&r| self.distances[r][key]).min())
+            .collect::<Vec<_>>();
+        let mut total = 0;
+        while !left.is_empty() {
+            let nearest = (0..left.len())
+                .filter_map(|index| best[index].map(|distance| (distance, index)))
+                .min();
+            let (distance, index) = match nearest {
+                Some(nearest) => nearest,
+                // The rest can't be reached, so this search goes nowhere anyway.
+                None => break,
+            };
+            total += distance;
+            let key = left.swap_remove(index);
+            best.swap_remove(index);
+            for (other, best) in left.iter().zip(best.iter_mut()) {
+                if let Some(distance) = self.distances[key][*other] {
+                    *best = Some(best.map_or(distance, |best| best.min(distance)));
+                }
+            }
+        }
+        total
+    }
+}
+
+fn unreachable_keys() -> Error {
+    Error::new("the keys can't all be collected")
+}
+
+// The index of the key that opens a door, if the vault has one.
+fn door_key(door: char, key_index: &HashMap<char, usize>) -> Option<usize> {
+    let mut lower = door.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(key), None) => key_index.get(&key).copied(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks that the simpler search agrees.
+    fn steps(map: &str) -> Answer {
+        let vault = Day18::parse(map).unwrap();
+        let answer = vault.part_one().unwrap();
+        assert_eq!(
+            Answer::from(vault.steps_without_graph(false).unwrap()),
+            answer
+        );
+        answer
+    }
+
+    fn robots(map: &str) -> Answer {
+        let vault = Day18::parse(map).unwrap();
+        let answer = vault.part_two().unwrap();
+        assert_eq!(
+            Answer::from(vault.steps_without_graph(true).unwrap()),
+            answer
+        );
+        answer
+    }
+
+    // The fewest steps to collect every key, moving a robot a tile at a time: slow, but too
+    // simple to get wrong.
+    fn tile_by_tile(vault: &Day18) -> Option<usize> {
+        let paths = search::bfs(SearchNode::new(vault.starts.clone()), |node| {
+            let mut next = Vec::new();
+            for (bot, position) in node.positions.iter().enumerate() {
+                for neighbour in position.neighbours() {
+                    let collected = match vault.map.get(neighbour) {
+                        None | Some(TileContent::Wall) => continue,
+                        Some(&TileContent::Door(d)) => {
+                            let key = door_key(d, &vault.key_index).unwrap();
+                            if !node.collected.contains(key) {
+                                continue;
+                            }
+                            node.collected
+                        }
+                        Some(&TileContent::Key(k)) => node.collected.with(vault.key_index[&k]),
+                        Some(_) => node.collected,
+                    };
+                    let mut positions = node.positions.clone();
+                    positions[bot] = neighbour;
+                    next.push(SearchNode {
+                        positions,
+                        collected,
+                    });
+                }
+            }
+            next
+        });
+        let distances = paths.distances().iter();
+        let done = distances.filter(|(node, _)| node.collected == vault.all_keys);
+        done.map(|(_, &distance)| distance).min()
+    }
+
+    #[test]
+    fn loops() {
+        // The shortest way to c passes the door to a: the way through B is longer.
+        let map = "#######\n#.B..c#\n#AC..##\n#.ba@.#\n#######";
+        assert_eq!(steps(map), Answer::Number(7));
+        // The shortest way from b to a needs its own key, but there's another through B.
+        let map = "#######\n#b.CA.#\n#.###a#\n#c.@B.#\n#######";
+        assert_eq!(steps(map), Answer::Number(11));
+
+        // Random small vaults, most of them with loops.
+        let mut seed = 0x2019_1218_u64;
+        let mut random = |n: usize| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            (seed % n as u64) as usize
+        };
+        for _ in 0..2000 {
+            let mut tiles = (0..15)
+                .map(|_| if random(3) == 0 { '#' } else { '.' })
+                .collect::<Vec<_>>();
+            let mut places = (0..15).collect::<Vec<_>>();
+            for index in (1..places.len()).rev() {
+                places.swap(index, random(index + 1));
+            }
+            let keys = 1 + random(3);
+            let doors = random(keys + 2);
+            let mut places = places.into_iter();
+            tiles[places.next().unwrap()] = '@';
+            for key in b"abc".iter().take(keys) {
+                tiles[places.next().unwrap()] = *key as char;
+            }
+            for _ in 0..doors {
+                tiles[places.next().unwrap()] = (b'A' + random(keys) as u8) as char;
+            }
+            let rows = tiles.chunks(5).map(|row| row.iter().collect::<String>());
+            let rows = rows.map(|row| format!("#{}#", row)).collect::<Vec<_>>();
+            let map = format!("#######\n{}\n#######", rows.join("\n"));
+
+            let vault = Day18::parse(&map).unwrap();
+            let expected = tile_by_tile(&vault);
+            let answer = vault.part_one().ok();
+            assert_eq!(answer, expected.map(Answer::from), "{}", map);
+            assert_eq!(vault.steps_without_graph(false).ok(), expected, "{}", map);
+            if let Ok(route) = vault.route(false) {
+                assert_eq!(route.walks[0].len() - 1, route.steps, "{}", map);
+            }
+        }
+    }
+
+    #[test]
+    fn one_robot() {
+        assert_eq!(steps("#########\n#b.A.@.a#\n#########"), Answer::Number(8));
+
+        let map = "\
+########################
+#f.D.E.e.C.b.A.@.a.B.c.#
+######################.#
+#d.....................#
+########################";
+        assert_eq!(steps(map), Answer::Number(86));
+
+        let map = "\
+########################
+#...............b.C.D.f#
+#.######################
+#.....@.a.B.c.d.A.e.F.g#
+########################";
+        assert_eq!(steps(map), Answer::Number(132));
+
+        let map = "\
+#################
+#i.G..c...e..H.p#
+########.########
+#j.A..b...f..D.o#
+########@########
+#k.E..a...g..B.n#
+########.########
+#l.F..d...h..C.m#
+#################";
+        assert_eq!(steps(map), Answer::Number(136));
+
+        let map = "\
+########################
+#@..............ac.GI.b#
+###d#e#f################
+###A#B#C################
+###g#h#i################
+########################";
+        assert_eq!(steps(map), Answer::Number(81));
+    }
+
+    #[test]
+    fn four_robots() {
+        let map = "\
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######";
+        assert_eq!(robots(map), Answer::Number(8));
+
+        let map = "\
+###############
+#d.ABC.#.....a#
+######...######
+######.@.######
+######...######
+#b.....#.....c#
+###############";
+        assert_eq!(robots(map), Answer::Number(24));
+
+        let map = "\
+#############
+#DcBa.#.GhKl#
+#.###...#I###
+#e#d#.@.#j#k#
+###C#...###J#
+#fEbA.#.FgHi#
+#############";
+        assert_eq!(robots(map), Answer::Number(32));
+
+        let map = "\
+#############
+#g#f.D#..h#l#
+#F###e#E###.#
+#dCba...BcIJ#
+#####.@.#####
+#nK.L...G...#
+#M###N#H###.#
+#o#m..#i#jk.#
+#############";
+        assert_eq!(robots(map), Answer::Number(72));
+    }
+
+    #[test]
+    fn several_entrances() {
+        let map = "\
+#######
+#a.#Cd#
+##@#@##
+#######
+##@#@##
+#cB#Ab#
+#######";
+        assert_eq!(steps(map), Answer::Number(8));
+        assert_eq!(robots(map), Answer::Number(8));
+    }
+
+    #[test]
+    fn many_keys() {
+        // Each key opens the door to the next, beyond the end of the alphabet.
+        let mut map = "#@".to_owned();
+        for key in "abcdefghijklmnopqrstuvwxyzà".chars() {
+            map.push(key);
+            map.extend(key.to_uppercase());
+        }
+        map.push_str("é#");
+        assert_eq!(steps(&map), Answer::Number(55));
+
+        let err = Day18::parse("#####\n#@.A#\n#####").err().unwrap();
+        assert_eq!(err.reason(), "no key for door 'A'");
+        assert!(Day18::parse("####\n#ab#\n####").is_err());
+
+        // Each key is behind the other's door.
+        let vault = Day18::parse("#######\n#bA@Ba#\n#######").unwrap();
+        let err = vault.part_one().err().unwrap();
+        assert_eq!(err.reason(), "the keys can't all be collected");
+        assert!(vault.steps_without_graph(false).is_err());
+    }
+
+    #[test]
+    fn routes() {
+        let vault = Day18::parse("#########\n#b.A.@.a#\n#########").unwrap();
+        let route = vault.route(false).unwrap();
+        assert_eq!(route.steps, 8);
+        assert_eq!(route.keys, vec![(0, 'a'), (0, 'b')]);
+        assert_eq!(route.walks[0].len(), 9);
+        let (right, left) = (Direction::Right, Direction::Left);
+        let mut directions = vec![right, right];
+        directions.extend(vec![left; 6]);
+        assert_eq!(route.directions(), vec![directions]);
+        assert_eq!(route.render(), "#########\n#b*A*@*a#\n#########");
+
+        let map = "\
+#######
+#a.#Cd#
+##...##
+##.@.##
+##...##
+#cB#Ab#
+#######";
+        let route = Day18::parse(map).unwrap().route(true).unwrap();
+        assert_eq!(route.steps, 8);
+        assert_eq!(route.keys, vec![(0, 'a'), (3, 'b'), (2, 'c'), (1, 'd')]);
+        assert!(route.walks.iter().all(|walk| walk.len() == 3));
+    }
+}
@@ -0,0 +1,73 @@
+//! Day 19: Tractor Beam.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+
+#[derive(Copy, Clone, Debug, PartialOrd, Ord, PartialEq, Eq, Hash)]
+struct Position(usize, usize);
+
+#[derive(Clone)]
+struct Drone {
+    program: IntCode,
+}
+
+impl Drone {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn probe(&mut self, position: Position) -> Result<i64, Error> {
+        self.program.add_input(position.0 as i64);
+        self.program.add_input(position.1 as i64);
+        self.program.execute()?;
+        self.program
+            .get_output()
+            .ok_or_else(|| Error::new("the drone did not report"))
+    }
+}
+
+/// The drone system's program.
+pub struct Day19 {
+    drone: Drone,
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        let drone = Drone::new(intcode);
+        Ok(Self { drone })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let answer = iproduct!(0..50, 0..50)
+            .map(|(x, y)| self.drone.clone().probe(Position(x, y)))
+            .sum::<Result<i64, _>>()?;
+        Ok(answer.into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        // Slow but straightforward.
+        let mut answer = None;
+        'outer: for sum in 0..2000 {
+            for x in 0..sum {
+                let y = sum - x;
+                if self.drone.clone().probe(Position(x, y))? == 0 {
+                    continue;
+                }
+                if self.drone.clone().probe(Position(x + 99, y))? == 0 {
+                    continue;
+                }
+                if self.drone.clone().probe(Position(x, y + 99))? == 0 {
+                    continue;
+                }
+                if self.drone.clone().probe(Position(x + 99, y + 99))? == 0 {
+                    continue;
+                }
+                answer.replace(10000 * x + y);
+                break 'outer;
+            }
+        }
+        let answer = answer.ok_or_else(|| Error::new("the ship does not fit in the beam"))?;
+        Ok(answer.into())
+    }
+}
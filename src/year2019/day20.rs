@@ -0,0 +1,324 @@
+//! Day 20: Donut Maze.
+use crate::error::Error;
+use crate::grid::{Direction, Grid, Point};
+use crate::search;
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum TileContent {
+    Wall,
+    Corridor,
+    Portal(String),
+    Space,
+    Label(char),
+}
+
+impl From<char> for TileContent {
+    fn from(x: char) -> Self {
+        // We can't recognize portals on first pass.
+        match x {
+            '#' => Self::Wall,
+            '.' => Self::Corridor,
+            ' ' => Self::Space,
+            c => Self::Label(c),
+        }
+    }
+}
+
+impl TileContent {
+    fn is_visitable(&self) -> bool {
+        matches!(self, Self::Corridor | Self::Portal(_))
+    }
+}
+
+struct Maze {
+    map: Grid<TileContent>,
+    portals: HashMap<String, Vec<Point>>,
+}
+
+impl Maze {
+    fn new(map: Grid<TileContent>, portals: HashMap<String, Vec<Point>>) -> Self {
+        Self { map, portals }
+    }
+
+    fn get_portal_partner(&self, position: &Point, label: &str) -> Option<Point> {
+        self.portals[label].iter().cloned().find(|p| p != position)
+    }
+
+    fn content(&self, position: &Point) -> Option<&TileContent> {
+        self.map.get(*position)
+    }
+
+    // Outer portals are on the edge of the donut, next to their labels on the edge of the map.
+    fn is_outer(&self, position: Point) -> bool {
+        let width = self.map.width() as i64;
+        let height = self.map.height() as i64;
+        position.x < 3 || position.y < 3 || position.x + 4 > width || position.y + 4 > height
+    }
+
+    fn get_portals(&self, label: &str) -> Option<&Vec<Point>> {
+        self.portals.get(label)
+    }
+
+    // Returns a hashmap with distances to all reachable places, starting from the start.
+    fn distances_from(&self, start: Point, allow_jumping: bool) -> HashMap<Point, usize> {
+        let paths = search::bfs(start, |position| {
+            let mut next = position
+                .neighbours()
+                .filter(|neighbour| self.content(neighbour).is_some_and(|p| p.is_visitable()))
+                .collect::<Vec<_>>();
+            if allow_jumping {
+                if let Some(TileContent::Portal(label)) = self.content(position) {
+                    next.extend(self.get_portal_partner(position, label));
+                }
+            }
+            next
+        });
+        paths.into_distances()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Node {
+    label: String,
+    outer: bool,
+}
+
+impl Node {
+    fn new(label: String, outer: bool) -> Self {
+        Self { label, outer }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct NodePoint {
+    node: Node,
+    level: usize,
+}
+
+impl NodePoint {
+    fn new(node: Node, level: usize) -> Self {
+        Self { node, level }
+    }
+}
+
+/// The maze, with its portals.
+pub struct Day20 {
+    maze: Maze,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        // Trailing spaces are easily lost, so short rows are taken to be padded with space.
+        let mut map =
+            Grid::parse_padded(input, ' ', |c| Ok::<_, Infallible>(TileContent::from(c)))?;
+
+        // Mark the portals, and create a table pairing them off.
+        mark_portals(&mut map);
+        let mut portals: HashMap<String, Vec<Point>> = HashMap::new();
+        for (position, content) in map.iter() {
+            if let TileContent::Portal(label) = content {
+                let positions = portals.entry(label.clone()).or_default();
+                positions.push(position);
+            }
+        }
+        let maze = Maze::new(map, portals);
+        for label in &["AA", "ZZ"] {
+            match maze.get_portals(label) {
+                Some(positions) if positions.iter().all(|&p| maze.is_outer(p)) => {}
+                Some(_) => {
+                    let reason = format!("{} is not on the outside of the maze", label);
+                    return Err(Error::new(reason));
+                }
+                None => return Err(Error::new(format!("no {} portal", label))),
+            }
+        }
+        Ok(Self { maze })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let maze = &self.maze;
+        let start = maze.get_portals("AA").expect("Maze has AA")[0];
+        let distances = maze.distances_from(start, true);
+        let end = maze.get_portals("ZZ").expect("Maze has ZZ")[0];
+        let distance = distances.get(&end).ok_or_else(no_path)?;
+        Ok((*distance).into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let maze = &self.maze;
+
+        // Start by constructing a map of the distances between portals.
+        let mut nodes: HashSet<Node> = HashSet::new();
+        let mut distance_table = HashMap::new();
+        for (position, content) in maze.map.iter() {
+            if let TileContent::Portal(label1) = content {
+                let node1 = Node::new(label1.to_owned(), maze.is_outer(position));
+                nodes.insert(node1.clone());
+                let distances = maze.distances_from(position, false);
+                let mut node_distances = HashMap::new();
+                for (label2, positions) in &maze.portals {
+                    for position2 in positions {
+                        let node2 = Node::new(label2.to_owned(), maze.is_outer(*position2));
+                        if let Some(distance) = distances.get(position2) {
+                            node_distances.insert(node2, *distance);
+                        }
+                    }
+                }
+                distance_table.insert(node1, node_distances);
+            }
+        }
+
+        // Now we search for the shortest path from AA on level 0 to ZZ on level 0.
+        let start = Node::new("AA".to_owned(), true);
+        let end = Node::new("ZZ".to_owned(), true);
+        let max_depth = nodes.iter().filter(|n| n.outer).count();
+        let successors = |position: &NodePoint| {
+            let mut next = Vec::new();
+            // A portal without a partner leads nowhere.
+            let distances = match distance_table.get(&position.node) {
+                Some(distances) => distances,
+                None => return next,
+            };
+            for node in &nodes {
+                if (position.level == 0) && node.outer && node != &end {
+                    continue;
+                }
+                if (position.level != 0) && (node == &start || node == &end) {
+                    continue;
+                }
+                if (position.level == max_depth) && !node.outer {
+                    continue;
+                }
+                if let Some(step) = distances.get(node) {
+                    let new_level = if !node.outer {
+                        position.level + 1
+                    } else if node != &end {
+                        position.level - 1
+                    } else {
+                        position.level
+                    };
+                    let new_outer = if node != &end {
+                        !node.outer
+                    } else {
+                        node.outer
+                    };
+                    let new_node = Node::new(node.label.clone(), new_outer);
+                    let mut new_distance = *step;
+                    if node != &end {
+                        new_distance += 1
+                    };
+                    next.push((NodePoint::new(new_node, new_level), new_distance));
+                }
+            }
+            next
+        };
+        let goal = NodePoint::new(end.clone(), 0);
+        let path = search::astar(
+            NodePoint::new(start.clone(), 0),
+            successors,
+            |_| 0,
+            |position| position == &goal,
+        );
+        Ok(path.ok_or_else(no_path)?.cost.into())
+    }
+}
+
+fn no_path() -> Error {
+    Error::new("there is no way from AA to ZZ")
+}
+
+// Updates the map with the positions of portals.
+fn mark_portals(map: &mut Grid<TileContent>) {
+    for position in map.points() {
+        let character = map
+            .get(position)
+            .and_then(|content| match content {
+                TileContent::Label(c1) => Some(c1),
+                _ => None,
+            })
+            .cloned();
+
+        if let Some(ch) = character {
+            let right = position.step(Direction::Right);
+            if let Some(TileContent::Label(c2)) = map.get(right) {
+                let label: String = [ch, *c2].iter().collect();
+                let right_again = right.step(Direction::Right);
+                if let Some(TileContent::Corridor) = map.get(right_again) {
+                    map[right_again] = TileContent::Portal(label);
+                } else if let Some(left) = map.get_mut(position.step(Direction::Left)) {
+                    *left = TileContent::Portal(label);
+                }
+            }
+
+            let down = position.step(Direction::Down);
+            if let Some(TileContent::Label(c2)) = map.get(down) {
+                let label: String = [ch, *c2].iter().collect();
+                let down_again = down.step(Direction::Down);
+                if let Some(TileContent::Corridor) = map.get(down_again) {
+                    map[down_again] = TileContent::Portal(label);
+                } else if let Some(up) = map.get_mut(position.step(Direction::Up)) {
+                    *up = TileContent::Portal(label);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "
+         A           
+         A           
+  #######.#########  
+  #######.........#  
+  #######.#######.#  
+  #######.#######.#  
+  #######.#######.#  
+  #####  B    ###.#  
+BC...##  C    ###.#  
+  ##.##       ###.#  
+  ##...DE  F  ###.#  
+  #####    G  ###.#  
+  #########.#####.#  
+DE..#######...###.#  
+  #.#########.###.#  
+FG..#########.....#  
+  ###########.#####  
+             Z       
+             Z       ";
+
+    #[test]
+    fn portals() {
+        let day = Day20::parse(&SMALL[1..]).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(23));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(26));
+    }
+
+    #[test]
+    fn trimmed() {
+        let trimmed = SMALL[1..]
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let day = Day20::parse(&trimmed).unwrap();
+        assert_eq!(day.part_one().unwrap(), Answer::Number(23));
+        assert_eq!(day.part_two().unwrap(), Answer::Number(26));
+    }
+
+    #[test]
+    fn no_way() {
+        let walled = "   A   Z   \n   A   Z   \n  #.###.#  \n  #######  ";
+        let day = Day20::parse(walled).unwrap();
+        assert_eq!(
+            day.part_one().err().unwrap().reason(),
+            "there is no way from AA to ZZ"
+        );
+        assert!(day.part_two().is_err());
+    }
+}
@@ -0,0 +1,130 @@
+//! Day 21: Springdroid Adventure.
+use crate::error::Error;
+use crate::intcode::{ExecuteResult, IntCode};
+use crate::solution::{Answer, Solution};
+use std::io::BufRead;
+
+#[derive(Clone)]
+struct SpringDroid {
+    program: IntCode,
+}
+
+impl SpringDroid {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn read_output(&mut self) -> Result<i64, String> {
+        let mut error = String::new();
+        while let Some(output) = self.program.get_output() {
+            if output > 255 {
+                return Ok(output);
+            }
+            error.push(output as u8 as char);
+        }
+        Err(error)
+    }
+
+    fn submit_line(&mut self, line: &str) {
+        for c in line.chars() {
+            self.program.add_input(c as u8 as i64);
+        }
+        self.program.add_input(b'\n' as i64);
+    }
+}
+
+impl SpringDroid {
+    // Runs a springscript program, returning the hull damage.
+    fn run(&mut self, script: &[&str]) -> Result<i64, Error> {
+        for line in script {
+            self.submit_line(line);
+        }
+        self.program.execute()?;
+        self.read_output()
+            .map_err(|_| Error::new("the droid fell into space"))
+    }
+}
+
+/// The springdroid's program.
+pub struct Day21 {
+    intcode: IntCode,
+}
+
+impl Solution for Day21 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    // Jump if: it's safe to land at D, and any of A, B, C is missing.
+    fn part_one(&self) -> Result<Answer, Error> {
+        let script = [
+            "OR A J",  // there is ground at A
+            "AND B J", // ... and at B
+            "AND C J", // ... and at C
+            "NOT J J", // there is ground missing at one of A, B, C
+            "AND D J", // and it's safe to land at D
+            "WALK",
+        ];
+        let mut droid = SpringDroid::new(self.intcode.clone());
+        Ok(droid.run(&script)?.into())
+    }
+
+    // Jump if: as above, unless E and H are both empty (for then we'll be stuck unable either to
+    // walk or to jump safely).
+    fn part_two(&self) -> Result<Answer, Error> {
+        let script = [
+            "OR A J", "AND B J", "AND C J", "NOT J J", "AND D J",
+            "OR E T",  // there's ground at E
+            "OR H T",  // or at H
+            "AND T J", // jump as above, if there is ground at E or H
+            "RUN",
+        ];
+        let mut droid = SpringDroid::new(self.intcode.clone());
+        Ok(droid.run(&script)?.into())
+    }
+}
+
+/// Type in springscript, and see how the droid gets on.  Enter "undo" (in any case) to take back
+/// the last instruction.
+pub fn play(input: &str) -> Result<(), Error> {
+    let intcode: IntCode = input.parse()?;
+    let mut droid = SpringDroid::new(intcode);
+    let mut checkpoints = vec![];
+    println!("Go!");
+    loop {
+        match droid.program.execute()? {
+            ExecuteResult::Done => {
+                let output = droid.read_output();
+                match output {
+                    Ok(answer) => {
+                        println!("Damage is: {}", answer);
+                        break;
+                    }
+                    Err(display) => {
+                        print!("{}", display);
+                        println!("Try again!");
+                        droid.program.reset();
+                        checkpoints.clear();
+                    }
+                }
+            }
+            ExecuteResult::InputNeeded => {}
+        }
+        let stdin = std::io::stdin();
+        let line = match stdin.lock().lines().next() {
+            Some(line) => line.map_err(|err| Error::new(format!("could not read: {}", err)))?,
+            None => break,
+        };
+        if line.trim().eq_ignore_ascii_case("undo") {
+            match checkpoints.pop() {
+                Some(checkpoint) => droid.program.restore(&checkpoint),
+                None => println!("Nothing to undo!"),
+            }
+            continue;
+        }
+        checkpoints.push(droid.program.mark());
+        droid.submit_line(&line);
+    }
+    Ok(())
+}
@@ -0,0 +1,276 @@
+//! Day 22: Slam Shuffle.
+use crate::error::{parse_lines, Error};
+use crate::solution::{Answer, Solution};
+use crate::util::{modular_inverse, modular_multiplication};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+enum Action {
+    Cut(i64),
+    Deal(u64),
+    NewStack,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseActionError;
+
+impl From<ParseIntError> for ParseActionError {
+    fn from(_err: ParseIntError) -> Self {
+        Self
+    }
+}
+
+impl FromStr for Action {
+    type Err = ParseActionError;
+
+    // cut 123
+    // deal with increment 345
+    // deal into new stack
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "deal into new stack" {
+            Ok(Action::NewStack)
+        } else if let Some(count) = s.strip_prefix("deal with increment ") {
+            match count.parse::<u64>()? {
+                0 => Err(ParseActionError),
+                n => Ok(Action::Deal(n)),
+            }
+        } else if let Some(count) = s.strip_prefix("cut ") {
+            Ok(Action::Cut(count.parse()?))
+        } else {
+            Err(ParseActionError)
+        }
+    }
+}
+
+impl Action {
+    // Each action corresponds to a linear mapping x -> mx + a.
+    fn linearize(&self, num_cards: u64) -> (u64, u64) {
+        match self {
+            Action::Cut(n) => {
+                let m = 1;
+                let a = if *n > 0 {
+                    num_cards - *n as u64
+                } else {
+                    -(*n) as u64
+                };
+                (m, a)
+            }
+            Action::Deal(n) => (*n, 0),
+            Action::NewStack => (num_cards - 1, num_cards - 1),
+        }
+    }
+}
+
+struct Deck {
+    cards: Vec<u32>,
+}
+
+impl Deck {
+    fn new(cards: Vec<u32>) -> Self {
+        Self { cards }
+    }
+
+    fn apply(&mut self, action: &Action) {
+        match *action {
+            Action::NewStack => self.cards.reverse(),
+            Action::Cut(n) => {
+                if n < 0 {
+                    self.cards.rotate_right(-n as usize)
+                } else {
+                    self.cards.rotate_left(n as usize)
+                }
+            }
+            Action::Deal(n) => {
+                let len = self.cards.len();
+                let mut cards = vec![0; len];
+                let position = (0usize..).step_by(n as usize);
+                for (c, p) in self.cards.iter().zip(position) {
+                    cards[p % len] = *c;
+                }
+                self.cards = cards;
+            }
+        }
+    }
+}
+
+// The size of the deck in part one.
+const DECK_SIZE: u64 = 10007;
+
+/// The shuffle process.
+pub struct Day22 {
+    actions: Vec<Action>,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let actions: Vec<Action> = parse_lines(input, "technique")?;
+        // Both decks have a prime number of cards, so any increment smaller than the first deck
+        // deals every card to its own position.
+        for (index, action) in actions.iter().enumerate() {
+            let size = match *action {
+                Action::Cut(n) => n.unsigned_abs(),
+                Action::Deal(n) => n,
+                Action::NewStack => 0,
+            };
+            if size >= DECK_SIZE {
+                let reason = format!("{} is too large for a deck of {} cards", size, DECK_SIZE);
+                return Err(Error::at_line(index + 1, reason));
+            }
+        }
+        Ok(Self { actions })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let cards = (0..DECK_SIZE as u32).collect();
+        let mut deck = Deck::new(cards);
+        for action in &self.actions {
+            deck.apply(action);
+        }
+        Ok(deck.cards.iter().position(|&n| n == 2019).unwrap().into())
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        // What is the combined effect of our actions, as x -> mx + a?
+        let num_cards = 119_315_717_514_047;
+        let (mult, add) = self.actions.iter().fold((1, 0), |acc, action| {
+            let pair = action.linearize(num_cards);
+            compose(num_cards, acc, pair)
+        });
+
+        // Calculate the inverse operation.
+        let imult = modular_inverse(num_cards, mult);
+        let iadd = modular_multiplication(num_cards, num_cards - add, imult);
+
+        // Calculate the effect of performing that inverse many times.
+        let repetitions = 101_741_582_076_661;
+        let (m, a) = repeatedly(num_cards, (imult, iadd), repetitions);
+
+        // Finally we can read out the answer.
+        let answer = (modular_multiplication(num_cards, m, 2020) + a) % num_cards;
+        Ok(answer.into())
+    }
+}
+
+fn compose(num_cards: u64, (m1, a1): (u64, u64), (m2, a2): (u64, u64)) -> (u64, u64) {
+    // m2 * (m1 x + a1) + a2 -> (m2 * m1)x + m2 * a1 + a2
+    let m = modular_multiplication(num_cards, m2, m1);
+    let a = (modular_multiplication(num_cards, m2, a1) + a2) % num_cards;
+    (m, a)
+}
+
+fn repeatedly(num_cards: u64, (mult, add): (u64, u64), count: u64) -> (u64, u64) {
+    // End result.
+    let mut m = 1;
+    let mut a = 0;
+
+    // Working values.
+    let mut xm = mult;
+    let mut xa = add;
+    let mut iterations = count;
+    while iterations != 0 {
+        if (iterations % 2) == 1 {
+            let (m1, a1) = compose(num_cards, (m, a), (xm, xa));
+            m = m1;
+            a = a1;
+        }
+        let (m1, a1) = compose(num_cards, (xm, xa), (xm, xa));
+        xm = m1;
+        xa = a1;
+        iterations /= 2;
+    }
+    (m, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shuffle(process: &str) -> Vec<u32> {
+        let mut deck = Deck::new((0..10).collect());
+        for line in process.lines() {
+            deck.apply(&line.parse().unwrap());
+        }
+        deck.cards
+    }
+
+    #[test]
+    fn techniques() {
+        assert_eq!(
+            shuffle("deal into new stack"),
+            [9, 8, 7, 6, 5, 4, 3, 2, 1, 0]
+        );
+        assert_eq!(shuffle("cut 3"), [3, 4, 5, 6, 7, 8, 9, 0, 1, 2]);
+        assert_eq!(shuffle("cut -4"), [6, 7, 8, 9, 0, 1, 2, 3, 4, 5]);
+        assert_eq!(
+            shuffle("deal with increment 3"),
+            [0, 7, 4, 1, 8, 5, 2, 9, 6, 3]
+        );
+    }
+
+    #[test]
+    fn processes() {
+        let process = "deal with increment 7\ndeal into new stack\ndeal into new stack";
+        assert_eq!(shuffle(process), [0, 3, 6, 9, 2, 5, 8, 1, 4, 7]);
+
+        let process = "cut 6\ndeal with increment 7\ndeal into new stack";
+        assert_eq!(shuffle(process), [3, 0, 7, 4, 1, 8, 5, 2, 9, 6]);
+
+        let process = "deal with increment 7\ndeal with increment 9\ncut -2";
+        assert_eq!(shuffle(process), [6, 3, 0, 7, 4, 1, 8, 5, 2, 9]);
+
+        let process = "\
+deal into new stack
+cut -2
+deal with increment 7
+cut 8
+cut -4
+deal with increment 7
+cut 3
+deal with increment 9
+deal with increment 3
+cut -1";
+        assert_eq!(shuffle(process), [9, 2, 5, 8, 1, 4, 7, 0, 3, 6]);
+    }
+
+    #[test]
+    fn invalid() {
+        for line in &[
+            "deal with increment 0",
+            "deal into old stack",
+            "deal 3",
+            "cut",
+            "shuffle",
+        ] {
+            assert_eq!(line.parse::<Action>().err(), Some(ParseActionError));
+        }
+        let err = Day22::parse("cut 3\ndeal with increment 0").err().unwrap();
+        assert_eq!(err.line(), Some(2));
+        let err = Day22::parse("cut -10007").err().unwrap();
+        assert_eq!(err.line(), Some(1));
+    }
+
+    // The linear form of a process should agree with actually shuffling, including when repeated.
+    #[test]
+    fn linearized() {
+        let process = "deal into new stack\ncut -2\ndeal with increment 7\ncut 8";
+        let actions = process
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect::<Vec<Action>>();
+        let num_cards = 11;
+        let (mult, add) = actions.iter().fold((1, 0), |acc, action| {
+            compose(num_cards, acc, action.linearize(num_cards))
+        });
+        let (mult, add) = repeatedly(num_cards, (mult, add), 3);
+
+        let mut deck = Deck::new((0..11).collect());
+        for _ in 0..3 {
+            for action in &actions {
+                deck.apply(action);
+            }
+        }
+        for (position, &card) in deck.cards.iter().enumerate() {
+            assert_eq!((mult * u64::from(card) + add) % num_cards, position as u64);
+        }
+    }
+}
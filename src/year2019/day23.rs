@@ -0,0 +1,140 @@
+//! Day 23: Category Six.
+use crate::error::Error;
+use crate::intcode::IntCode;
+use crate::solution::{Answer, Solution};
+use std::convert::TryFrom;
+
+// The address that a packet is sent to, and the packet.
+type Message = (i64, (i64, i64));
+
+#[derive(Clone)]
+struct Computer {
+    program: IntCode,
+}
+
+impl Computer {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn initialize(&mut self, address: i64) {
+        self.program.add_input(address);
+        self.program.add_input(-1);
+    }
+
+    fn read_message(&mut self) -> Result<Option<Message>, Error> {
+        let address = match self.program.get_output() {
+            Some(address) => address,
+            None => return Ok(None),
+        };
+        let mut next = || {
+            self.program
+                .get_output()
+                .ok_or_else(|| Error::new("a computer sent part of a packet"))
+        };
+        Ok(Some((address, (next()?, next()?))))
+    }
+
+    fn queue_packet(&mut self, (x, y): (i64, i64)) {
+        self.program.add_input(x);
+        self.program.add_input(y);
+    }
+
+    fn execute(&mut self) -> Result<Vec<Message>, Error> {
+        self.program.execute()?;
+        let mut messages = vec![];
+        while let Some(message) = self.read_message()? {
+            messages.push(message);
+        }
+        Ok(messages)
+    }
+}
+
+/// The network interface controller software.
+pub struct Day23 {
+    intcode: IntCode,
+}
+
+impl Day23 {
+    fn network(&self) -> Vec<Computer> {
+        let mut computers = (0..50)
+            .map(|_| Computer::new(self.intcode.clone()))
+            .collect::<Vec<_>>();
+
+        for (address, computer) in computers.iter_mut().enumerate() {
+            computer.initialize(address as i64);
+        }
+        computers
+    }
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut computers = self.network();
+        loop {
+            let mut activity = false;
+            for idx in 0..50 {
+                let results = computers[idx].execute()?;
+                for &(address, packet) in &results {
+                    if address == 255 {
+                        let (_, y) = packet;
+                        return Ok(y.into());
+                    }
+                    activity = true;
+                    destination(&mut computers, address)?.queue_packet(packet);
+                }
+            }
+            if !activity {
+                return Err(Error::new(
+                    "the network is idle, and nothing was sent to 255",
+                ));
+            }
+        }
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        let mut computers = self.network();
+        let mut nat_packet = None;
+        let mut last_nat_y = None;
+        loop {
+            let mut activity = false;
+            for idx in 0..50 {
+                let results = computers[idx].execute()?;
+                for &(address, packet) in &results {
+                    if address == 255 {
+                        nat_packet = Some(packet);
+                    } else {
+                        activity = true;
+                        destination(&mut computers, address)?.queue_packet(packet);
+                    }
+                }
+            }
+
+            if !activity {
+                let packet = nat_packet
+                    .ok_or_else(|| Error::new("the network is idle, and the NAT has no packet"))?;
+                let (_, y) = packet;
+                if last_nat_y == Some(y) {
+                    return Ok(y.into());
+                }
+                last_nat_y = Some(y);
+
+                let zero = &mut computers[0];
+                zero.queue_packet(packet);
+            }
+        }
+    }
+}
+
+// The computer that a packet is addressed to.
+fn destination(computers: &mut [Computer], address: i64) -> Result<&mut Computer, Error> {
+    usize::try_from(address)
+        .ok()
+        .and_then(move |index| computers.get_mut(index))
+        .ok_or_else(|| Error::new(format!("no computer has address {}", address)))
+}
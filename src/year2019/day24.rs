@@ -1,6 +1,8 @@
+//! Day 24: Planet of Discord.
+use crate::error::Error;
+use crate::solution::{Answer, Solution};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::convert::TryFrom;
 
 #[derive(Copy, Clone, Debug, Hash, PartialEq, Eq)]
 enum TileContent {
@@ -8,11 +10,17 @@ enum TileContent {
     Space,
 }
 
-impl From<char> for TileContent {
-    fn from(x: char) -> Self {
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct ParseTileError;
+
+impl TryFrom<char> for TileContent {
+    type Error = ParseTileError;
+
+    fn try_from(x: char) -> Result<Self, Self::Error> {
         match x {
-            '#' => Self::Bug,
-            _ => Self::Space,
+            '#' => Ok(Self::Bug),
+            '.' => Ok(Self::Space),
+            _ => Err(ParseTileError),
         }
     }
 }
@@ -126,13 +134,13 @@ impl MultiGrid {
                 }
             }
             if self.grids[idx - 1].cells[1][2] == TileContent::Bug {
-                for y in 0..5 {
-                    count[0][y] += 1;
+                for cell in count[0].iter_mut() {
+                    *cell += 1;
                 }
             }
             if self.grids[idx - 1].cells[3][2] == TileContent::Bug {
-                for y in 0..5 {
-                    count[4][y] += 1;
+                for cell in count[4].iter_mut() {
+                    *cell += 1;
                 }
             }
             if self.grids[idx - 1].cells[2][3] == TileContent::Bug {
@@ -181,34 +189,72 @@ impl MultiGrid {
     }
 }
 
-pub(crate) fn day24() {
-    let input = File::open("data/day24.txt").expect("Failed to open input");
-    let buffered = BufReader::new(input);
-    let mut cells = [[TileContent::Space; 5]; 5];
-    for (y, line) in buffered.lines().enumerate() {
-        for (x, c) in line.unwrap().chars().enumerate() {
-            cells[x][y] = TileContent::from(c);
+/// The initial scan of bugs.
+pub struct Day24 {
+    cells: [[TileContent; 5]; 5],
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let mut cells = [[TileContent::Space; 5]; 5];
+        let lines = input.lines().collect::<Vec<_>>();
+        if lines.len() != 5 {
+            return Err(Error::new("expected a 5x5 grid"));
+        }
+        for (y, line) in lines.into_iter().enumerate() {
+            if line.chars().count() != 5 {
+                return Err(Error::at_line(y + 1, "expected a 5x5 grid"));
+            }
+            for (x, c) in line.chars().enumerate() {
+                cells[x][y] = TileContent::try_from(c)
+                    .map_err(|_| Error::at_line(y + 1, format!("unexpected tile: {:?}", c)))?;
+            }
+        }
+        Ok(Self { cells })
+    }
+
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut grid = Grid::new(self.cells);
+        let mut seen = HashSet::new();
+        loop {
+            let biodiversity = grid.biodiversity();
+            if !seen.insert(biodiversity) {
+                return Ok(biodiversity.into());
+            }
+            grid.evolve();
         }
     }
 
-    // Part one.
-    let mut grid = Grid::new(cells);
-    let mut seen = HashSet::new();
-    loop {
-        let biodiversity = grid.biodiversity();
-        if !seen.insert(biodiversity) {
-            println!("Part one answer is: {}", biodiversity);
-            break;
+    fn part_two(&self) -> Result<Answer, Error> {
+        let starter = Grid::new(self.cells);
+        let mut multigrid = MultiGrid::new(starter);
+        for _ in 0..200 {
+            multigrid.evolve();
         }
-        grid.evolve();
+        Ok(multigrid.count_bugs().into())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    // Part two.
-    let starter = Grid::new(cells);
-    let mut multigrid = MultiGrid::new(starter);
-    for _ in 0..200 {
-        multigrid.evolve();
+    const SCAN: &str = "....#\n#..#.\n#..##\n..#..\n#....";
+
+    #[test]
+    fn first_repeat() {
+        assert_eq!(
+            Day24::parse(SCAN).unwrap().part_one().unwrap(),
+            Answer::Number(2_129_920)
+        );
+    }
+
+    #[test]
+    fn recursive() {
+        let mut multigrid = MultiGrid::new(Grid::new(Day24::parse(SCAN).unwrap().cells));
+        for _ in 0..10 {
+            multigrid.evolve();
+        }
+        assert_eq!(multigrid.count_bugs(), 99);
     }
-    let answer = multigrid.count_bugs();
-    println!("Part two answer is: {}", answer);
 }
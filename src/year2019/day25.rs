@@ -0,0 +1,255 @@
+//! Day 25: Cryostasis.
+use crate::error::Error;
+use crate::intcode::{ExecuteResult, IntCode};
+use crate::solution::{Answer, Solution};
+use std::io::BufRead;
+
+#[derive(Clone)]
+struct Droid {
+    program: IntCode,
+}
+
+impl Droid {
+    fn new(program: IntCode) -> Self {
+        Self { program }
+    }
+
+    fn read_output(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(output) = self.program.get_output() {
+            text.push(output as u8 as char);
+        }
+        text
+    }
+
+    fn submit_line(&mut self, line: &str) {
+        for c in line.chars() {
+            self.program.add_input(c as u8 as i64);
+        }
+        self.program.add_input(b'\n' as i64);
+    }
+}
+
+// Items that it would be a mistake to pick up.
+const DANGEROUS_ITEMS: &[&str] = &[
+    "escape pod",
+    "giant electromagnet",
+    "infinite loop",
+    "molten lava",
+    "photons",
+];
+
+const CHECKPOINT: &str = "Security Checkpoint";
+
+// What the droid can see in a room.
+struct Room {
+    name: String,
+    doors: Vec<String>,
+    items: Vec<String>,
+}
+
+impl Room {
+    // Reads the description of the last room mentioned in some output.
+    fn from_output(output: &str) -> Result<Self, Error> {
+        let start = output
+            .rfind("== ")
+            .ok_or_else(|| Error::new("the droid is not in a room"))?;
+        let mut lines = output[start..].lines();
+        let name = lines.next().unwrap_or_default();
+        let name = name.trim_matches(|c| c == '=' || c == ' ');
+        let mut doors = vec![];
+        let mut items = vec![];
+        let mut list = None;
+        for line in lines {
+            match line {
+                "Doors here lead:" => list = Some(&mut doors),
+                "Items here:" => list = Some(&mut items),
+                _ => match (line.strip_prefix("- "), list.as_mut()) {
+                    (Some(entry), Some(list)) => list.push(entry.to_owned()),
+                    _ => list = None,
+                },
+            }
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            doors,
+            items,
+        })
+    }
+}
+
+fn opposite(direction: &str) -> Result<&'static str, Error> {
+    match direction {
+        "north" => Ok("south"),
+        "south" => Ok("north"),
+        "east" => Ok("west"),
+        "west" => Ok("east"),
+        _ => Err(Error::new(format!("unknown direction: {:?}", direction))),
+    }
+}
+
+impl Droid {
+    fn command(&mut self, line: &str) -> Result<String, Error> {
+        self.submit_line(line);
+        self.program.execute()?;
+        Ok(self.read_output())
+    }
+
+    // Walks the ship depth-first, picking up everything that's safe to carry, and returns to where
+    // it started.  Records the route to the security checkpoint, and the direction onwards from
+    // there.
+    fn explore(
+        &mut self,
+        room: &Room,
+        came_from: Option<&str>,
+        route: &mut Vec<String>,
+        checkpoint: &mut Option<(Vec<String>, String)>,
+    ) -> Result<(), Error> {
+        for item in &room.items {
+            if !DANGEROUS_ITEMS.contains(&item.as_str()) {
+                self.command(&format!("take {}", item))?;
+            }
+        }
+        for door in &room.doors {
+            if Some(door.as_str()) == came_from {
+                continue;
+            }
+            if room.name == CHECKPOINT {
+                *checkpoint = Some((route.clone(), door.clone()));
+                continue;
+            }
+            let back = opposite(door)?;
+            let next = Room::from_output(&self.command(door)?)?;
+            route.push(door.clone());
+            self.explore(&next, Some(back), route, checkpoint)?;
+            route.pop();
+            self.command(back)?;
+        }
+        Ok(())
+    }
+
+    fn inventory(&mut self) -> Result<Vec<String>, Error> {
+        let items = self
+            .command("inv")?
+            .lines()
+            .filter_map(|line| line.strip_prefix("- "))
+            .map(|item| item.to_owned())
+            .collect();
+        Ok(items)
+    }
+}
+
+/// The droid's program.
+pub struct Day25 {
+    intcode: IntCode,
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self, Error> {
+        let intcode = input.parse()?;
+        Ok(Self { intcode })
+    }
+
+    // Collect everything, carry it to the checkpoint, and try each combination of items until the
+    // sensor is satisfied.
+    fn part_one(&self) -> Result<Answer, Error> {
+        let mut droid = Droid::new(self.intcode.clone());
+        droid.program.execute()?;
+        let start = Room::from_output(&droid.read_output())?;
+        let mut checkpoint = None;
+        droid.explore(&start, None, &mut vec![], &mut checkpoint)?;
+        let (route, onwards) =
+            checkpoint.ok_or_else(|| Error::new("could not find the security checkpoint"))?;
+        for door in &route {
+            droid.command(door)?;
+        }
+
+        let items = droid.inventory()?;
+        for item in &items {
+            droid.command(&format!("drop {}", item))?;
+        }
+        let subsets = 1u32
+            .checked_shl(items.len() as u32)
+            .ok_or_else(|| Error::new("too many items to try every combination"))?;
+        for subset in 0..subsets {
+            let mut attempt = droid.clone();
+            for (index, item) in items.iter().enumerate() {
+                if subset & (1 << index) != 0 {
+                    attempt.command(&format!("take {}", item))?;
+                }
+            }
+            let output = attempt.command(&onwards)?;
+            if let Some(password) = output
+                .split("typing ")
+                .nth(1)
+                .and_then(|rest| rest.split_whitespace().next())
+            {
+                let password = password
+                    .parse::<i64>()
+                    .map_err(|_| Error::new(format!("invalid password: {:?}", password)))?;
+                return Ok(password.into());
+            }
+        }
+        Err(Error::new(
+            "no combination of items gets past the checkpoint",
+        ))
+    }
+
+    fn part_two(&self) -> Result<Answer, Error> {
+        Ok("Merry Christmas!".to_owned().into())
+    }
+}
+
+/// Explore the ship by hand.  Enter "undo" (in any case) to take back the last command, even if
+/// it ended the game.
+pub fn play(input: &str) -> Result<(), Error> {
+    let intcode: IntCode = input.parse()?;
+
+    let mut droid = Droid::new(intcode);
+    let mut checkpoints = vec![];
+    println!("Go!");
+    loop {
+        let result = droid.program.execute()?;
+        let output = droid.read_output();
+        print!("{}", output);
+        if result == ExecuteResult::Done {
+            println!("Game over");
+            if checkpoints.is_empty() {
+                break;
+            }
+        }
+        let stdin = std::io::stdin();
+        let line = match stdin.lock().lines().next() {
+            Some(line) => line.map_err(|err| Error::new(format!("could not read: {}", err)))?,
+            None => break,
+        };
+        if line.trim().eq_ignore_ascii_case("undo") {
+            match checkpoints.pop() {
+                Some(checkpoint) => droid.program.restore(&checkpoint),
+                None => println!("Nothing to undo!"),
+            }
+            continue;
+        }
+        if result == ExecuteResult::Done {
+            break;
+        }
+        checkpoints.push(droid.program.mark());
+        droid.submit_line(&line);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn room() {
+        let output = "\n\n\n== Hull Breach ==\nYou got in through a hole in the floor here.\n\n\
+                      Doors here lead:\n- north\n- south\n\nItems here:\n- mug\n\nCommand?\n";
+        let room = Room::from_output(output).unwrap();
+        assert_eq!(room.name, "Hull Breach");
+        assert_eq!(room.doors, ["north", "south"]);
+        assert_eq!(room.items, ["mug"]);
+    }
+}